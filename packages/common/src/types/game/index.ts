@@ -2,33 +2,203 @@ export type Player = {
   id: string
   clientId: string
   connected: boolean
+  muted: boolean
   username: string
+  locale?: string
   points: number
+  correctAnswers: number
+  streak: number
+  lifelines: Record<Lifeline, number>
 }
 
+export type LeaderboardEntry = Pick<
+  Player,
+  "id" | "username" | "points" | "correctAnswers"
+> & { formattedPoints: string }
+
+// Upper bound on a question's answers, so layouts and answer checks stay
+// bounded whatever the quiz contains
+export const MAX_ANSWER_OPTIONS = 6
+
+export const LIFELINES = ["fiftyFifty", "extraTime"] as const
+
+export type Lifeline = (typeof LIFELINES)[number]
+
+export const CONFIDENCE_LEVELS = ["low", "medium", "high"] as const
+
+export type Confidence = (typeof CONFIDENCE_LEVELS)[number]
+
 export type Answer = {
   playerId: string
-  answerId: number
+  answerIds: number[]
+  value?: number
   points: number
+  confidence?: Confidence
+  // Milliseconds from the start of the question
+  elapsed: number
+}
+
+export type NumericSummary = {
+  count: number
+  min: number
+  max: number
+  median: number
+}
+
+export type MediaKind = "image" | "video" | "audio"
+
+export type MediaInfo = {
+  type?: string
+  width?: number
+  height?: number
+  duration?: number
+}
+
+export type QuestionMedia = Partial<Record<MediaKind, MediaInfo>>
+
+export type PinPosition = "first" | "last"
+
+export type Question = {
+  question: string
+  type?: "truefalse" | "numeric"
+  section?: string
+  pinned?: PinPosition
+  warmup?: boolean
+  tags?: string[]
+  image?: string
+  video?: string
+  audio?: string
+  media?: QuestionMedia
+  wager?: boolean
+  answers: string[]
+  solution: number | number[]
+  minSelect?: number
+  maxSelect?: number
+  cooldown: number
+  time: number
+  maxPoints?: number
+  target?: number
+  tolerance?: number
 }
 
 export type Quizz = {
   subject: string
-  questions: {
-    question: string
-    image?: string
-    video?: string
-    audio?: string
-    answers: string[]
-    solution: number
-    cooldown: number
-    time: number
-  }[]
+  inviteCode?: string
+  shuffleQuestions?: boolean
+  allowAnswerChange?: boolean
+  warmup?: Question
+  questions: Question[]
 }
 
 export type QuizzWithId = Quizz & { id: string }
 
+export type QuestionStats = {
+  question: string
+  warmup?: boolean
+  tags: string[]
+  answers: string[]
+  solution: number | number[]
+  responses: number[]
+  results: Record<string, { correct: boolean; points: number }>
+}
+
+export type QuestionReview = Omit<QuestionStats, "results"> & {
+  answered: number
+  correctCount: number
+}
+
+export type TagStats = {
+  tag: string
+  correct: number
+  total: number
+}
+
+export type Timings = {
+  start: number
+  startCountdown: number
+  prepared: number
+}
+
+// Delays in seconds before the game moves on by itself, 0 to wait for the
+// manager
+export type Pacing = {
+  autoStart: number
+  autoLeaderboard: number
+  autoNextQuestion: number
+  managerOverride: boolean
+}
+
+export type ScoringMode = "linear" | "fastBonus"
+
+export type Scoring = {
+  mode: ScoringMode
+  fastWindow: number
+  decay: "linear" | "exponential"
+}
+
+export type QuizzMarkup = "escape" | "formatting"
+
+export type PointsFormat = "raw" | "grouped" | "compact"
+
+export type LeaderboardMode = "points" | "accuracy"
+
+export type StaleAnswerAction = "reset" | "error" | "ignore"
+
+export type LobbyOrder = "join" | "alphabetical" | "random"
+
+export type GameConfig = {
+  managerPassword?: string
+  music: boolean
+  hidePoints: boolean
+  showManagerLeaderboard: boolean
+  showAnswererNames: boolean
+  hideAnswerTiming: boolean
+  hideAnswersUntilLock: boolean
+  showQuestionOnDevices: boolean
+  managerTeleprompter: boolean
+  minAnswerTime: number
+  allAnsweredGrace: number
+  answerCountInterval: number
+  pacing: Pacing
+  gameReview: boolean
+  maxGameDuration: number
+  allowReconnect: boolean
+  reconnectGrace: number
+  recordEvents: boolean
+  anonymizeExports: boolean
+  pauseOnDisconnect: boolean
+  pauseTimeout: number
+  allDisconnected: "continue" | "pause" | "end"
+  allDisconnectedTimeout: number
+  maxPlayers?: number
+  capacityWarnings: number[]
+  lobbyOrder: LobbyOrder
+  showLobbyPlayers: boolean
+  rematchKeepPlayers: boolean
+  feedbackMessages: { correct: string[]; wrong: string[] }
+  askConfidence: boolean
+  confidenceMultipliers: Record<Confidence, { correct: number; wrong: number }>
+  pointsDisplay: "total" | "round"
+  pointsFormat: PointsFormat
+  allowNegativePoints: boolean
+  quizzMarkup: QuizzMarkup
+  leaderboardMode: LeaderboardMode
+  stagedReveal: boolean
+  lifelines: Record<Lifeline, number>
+  extraTimeSeconds: number
+  timings: Timings
+  scoring: Scoring
+  scorer: string
+  staleAnswer: StaleAnswerAction
+}
+
 export type GameUpdateQuestion = {
   current: number
   total: number
 }
+
+export type ServerInfo = {
+  version: string
+  commit: string | null
+  maxAnswerOptions: number
+}
@@ -1,5 +1,15 @@
 import { Server as ServerIO, Socket as SocketIO } from "socket.io"
-import { GameUpdateQuestion, Player, QuizzWithId } from "."
+import {
+  Confidence,
+  GameUpdateQuestion,
+  LeaderboardEntry,
+  Lifeline,
+  LobbyOrder,
+  Player,
+  QuestionReview,
+  QuizzWithId,
+  ServerInfo,
+} from "."
 import { Status, StatusDataMap } from "./status"
 
 export type Server = ServerIO<ClientToServerEvents, ServerToClientEvents>
@@ -24,35 +34,83 @@ export interface ServerToClientEvents {
   connect: () => void
 
   // Game events
-  "game:status": (_data: { name: Status; data: StatusDataMap[Status] }) => void
+  "game:serverInfo": (_info: ServerInfo) => void
+  "game:status": (_data: {
+    gameId: string
+    name: Status
+    data: StatusDataMap[Status]
+  }) => void
   "game:successRoom": (_data: string) => void
-  "game:successJoin": (_gameId: string) => void
-  "game:totalPlayers": (_count: number) => void
+  "game:successJoin": (_data: { gameId: string; token: string }) => void
+  "game:totalPlayers": (_data: { gameId: string; count: number }) => void
+  "game:lobbyPlayers": (_data: {
+    gameId: string
+    usernames: string[]
+  }) => void
   "game:errorMessage": (_message: string) => void
-  "game:startCooldown": () => void
-  "game:cooldown": (_count: number) => void
-  "game:reset": (_message: string) => void
-  "game:updateQuestion": (_data: { current: number; total: number }) => void
-  "game:playerAnswer": (_count: number) => void
+  "game:protocolError": (_message: string) => void
+  "game:startCooldown": (_data: { gameId: string }) => void
+  "game:cooldown": (_data: { gameId: string; count: number }) => void
+  // Sockets that aren't in a game, e.g. an expired session, get no gameId
+  "game:reset": (_data: { gameId?: string; message: string }) => void
+  "game:updateQuestion": (_data: {
+    gameId: string
+    current: number
+    total: number
+  }) => void
+  "game:playerAnswer": (_data: { gameId: string; count: number }) => void
+  "game:answersUnlocked": (_data: { gameId: string }) => void
+  "game:paused": (_data: { gameId: string; username: string }) => void
+  "game:resumed": (_data: { gameId: string }) => void
+  "game:pong": (_nonce: string) => void
 
   // Player events
   "player:successReconnect": (_data: {
     gameId: string
+    token: string
     status: { name: Status; data: StatusDataMap[Status] }
-    player: { username: string; points: number }
+    player: { username: string; points?: number }
     currentQuestion: GameUpdateQuestion
   }) => void
-  "player:updateLeaderboard": (_data: { leaderboard: Player[] }) => void
+  "player:updateLeaderboard": (_data: {
+    leaderboard: LeaderboardEntry[]
+  }) => void
+  "player:wagerSet": (_amount: number) => void
+  "player:pointsAdjusted": (_data: {
+    points: number
+    formattedPoints: string
+  }) => void
+  "player:muted": (_muted: boolean) => void
+  "player:message": (_message: string) => void
+  "player:lifelines": (_lifelines: Record<Lifeline, number>) => void
+  "player:rematch": (_data: { gameId: string; keepName: boolean }) => void
+  "player:standings": (_data: {
+    rank: number
+    points: number
+    formattedPoints: string
+    top: LeaderboardEntry[]
+  }) => void
 
   // Manager events
   "manager:successReconnect": (_data: {
     gameId: string
+    token: string
+    seed: number
     status: { name: Status; data: StatusDataMap[Status] }
     players: Player[]
     currentQuestion: GameUpdateQuestion
   }) => void
   "manager:quizzList": (_quizzList: QuizzWithId[]) => void
-  "manager:gameCreated": (_data: { gameId: string; inviteCode: string }) => void
+  "manager:gameCreated": (_data: {
+    gameId: string
+    token: string
+    inviteCode: string
+    subject: string
+    lobbyOrder: LobbyOrder
+    seed: number
+  }) => void
+  "manager:quizzOutline": (_titles: string[]) => void
+  "manager:gameReview": (_review: QuestionReview[]) => void
   "manager:statusUpdate": (_data: {
     status: Status
     data: StatusDataMap[Status]
@@ -61,27 +119,93 @@ export interface ServerToClientEvents {
   "manager:removePlayer": (_playerId: string) => void
   "manager:errorMessage": (_message: string) => void
   "manager:playerKicked": (_playerId: string) => void
+  "manager:playerMuted": (_data: { playerId: string; muted: boolean }) => void
+  "manager:playerAnswered": (_username: string) => void
+  "manager:playerReconnected": (_data: {
+    oldId: string
+    player: Player
+  }) => void
+  "manager:playerDisconnected": (_player: Player) => void
+  "manager:allPlayersDisconnected": (_data: {
+    action: "pause" | "end"
+    timeout: number
+  }) => void
+  "manager:capacityWarning": (_data: {
+    count: number
+    maxPlayers: number
+    threshold: number
+  }) => void
 }
 
 export interface ClientToServerEvents {
   // Manager actions
-  "game:create": (_quizzId: string) => void
+  "game:create": (
+    _quizzId: string,
+    _inviteCode?: string,
+    _seed?: number,
+  ) => void
+  "game:createRandom": (_inviteCode?: string, _tag?: string) => void
   "manager:auth": (_password: string) => void
-  "manager:reconnect": (_message: { gameId: string }) => void
+  "manager:joinAsCoHost": (_message: {
+    gameId: string
+    password: string
+  }) => void
   "manager:kickPlayer": (_message: { gameId: string; playerId: string }) => void
+  "manager:mutePlayer": (_message: { gameId: string; playerId: string }) => void
+  "manager:messagePlayer": (_message: {
+    gameId: string
+    playerId: string
+    message: string
+  }) => void
+  "manager:adjustScore": (_message: {
+    gameId: string
+    playerId: string
+    delta: number
+  }) => void
+  "manager:unmutePlayer": (_message: {
+    gameId: string
+    playerId: string
+  }) => void
   "manager:startGame": (_message: MessageGameId) => void
   "manager:abortQuiz": (_message: MessageGameId) => void
   "manager:nextQuestion": (_message: MessageGameId) => void
   "manager:showLeaderboard": (_message: MessageGameId) => void
+  "manager:eliminateAnswer": (_message: MessageGameId) => void
+  "manager:redoQuestion": (_message: MessageGameId) => void
+  "manager:rematch": (
+    _message: MessageGameId & { keepPlayers?: boolean }
+  ) => void
 
   // Player actions
   "player:join": (_inviteCode: string) => void
-  "player:login": (_message: MessageWithoutStatus<{ username: string }>) => void
-  "player:reconnect": (_message: { gameId: string }) => void
+  "player:login": (
+    _message: MessageWithoutStatus<{ username: string; locale?: string }>
+  ) => void
   "player:selectedAnswer": (
-    _message: MessageWithoutStatus<{ answerKey: number }>
+    _message: MessageWithoutStatus<{
+      answerKey?: number
+      answerKeys?: number[]
+      confidence?: Confidence
+      questionIndex: number
+    }>
+  ) => void
+  "player:submitNumeric": (
+    _message: MessageWithoutStatus<{
+      value: number
+      confidence?: Confidence
+      questionIndex: number
+    }>
+  ) => void
+  "player:setWager": (
+    _message: MessageWithoutStatus<{ amount: number }>
+  ) => void
+  "player:useLifeline": (
+    _message: MessageWithoutStatus<{ kind: Lifeline }>
   ) => void
+  "player:requestStandings": (_message: MessageGameId) => void
 
   // Common
+  "game:reconnect": (_token: string) => void
+  "game:ping": (_nonce: string) => void
   disconnect: () => void
 }
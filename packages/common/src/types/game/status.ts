@@ -1,11 +1,21 @@
-import { Player } from "."
+import {
+  LeaderboardEntry,
+  LeaderboardMode,
+  LobbyOrder,
+  NumericSummary,
+  Player,
+  QuestionMedia,
+  TagStats,
+} from "."
 
 export const STATUS = {
   SHOW_ROOM: "SHOW_ROOM",
   SHOW_START: "SHOW_START",
+  SHOW_SECTION: "SHOW_SECTION",
   SHOW_PREPARED: "SHOW_PREPARED",
   SHOW_QUESTION: "SHOW_QUESTION",
   SELECT_ANSWER: "SELECT_ANSWER",
+  SHOW_ELIMINATION: "SHOW_ELIMINATION",
   SHOW_RESULT: "SHOW_RESULT",
   SHOW_RESPONSES: "SHOW_RESPONSES",
   SHOW_LEADERBOARD: "SHOW_LEADERBOARD",
@@ -17,40 +27,103 @@ export type Status = (typeof STATUS)[keyof typeof STATUS]
 
 export type CommonStatusDataMap = {
   SHOW_START: { time: number; subject: string }
-  SHOW_PREPARED: { totalAnswers: number; questionNumber: number }
-  SHOW_QUESTION: { question: string; image?: string; cooldown: number }
+  SHOW_SECTION: { title: string; questionNumber: number }
+  SHOW_PREPARED: {
+    totalAnswers: number
+    questionNumber: number
+    section?: string
+    isLast?: boolean
+    warmup?: boolean
+    answers?: string[]
+    solution?: number[]
+  }
+  SHOW_QUESTION: {
+    question: string
+    section?: string
+    isLast?: boolean
+    answers?: string[]
+    solution?: number[]
+    image?: string
+    media?: QuestionMedia
+    wager?: boolean
+    cooldown: number
+  }
   SELECT_ANSWER: {
     question: string
+    section?: string
+    isLast?: boolean
     answers: string[]
     image?: string
     video?: string
     audio?: string
+    media?: QuestionMedia
+    wager?: boolean
     time: number
+    lockTime: number
+    askConfidence: boolean
     totalPlayer: number
+    validKeys: number[]
+    removedAnswers?: number[]
+    multiple?: { min: number; max: number }
+    numeric?: boolean
+    allowAnswerChange?: boolean
+    // Sent back with the answer, from 0
+    questionIndex: number
+  }
+  SHOW_ELIMINATION: {
+    question: string
+    answers: string[]
+    image?: string
+    eliminated: number[]
   }
   SHOW_RESULT: {
     correct: boolean
     message: string
-    points: number
-    myPoints: number
-    rank: number
-    aheadOfMe: string | null
+    points?: number
+    myPoints?: number
+    formattedPoints?: string
+    formattedMyPoints?: string
+    rank?: number
+    aheadOfMe?: string | null
+    streak: number
   }
   WAIT: { text: string }
-  FINISHED: { subject: string; top: Player[] }
+  FINISHED: {
+    subject: string
+    top: LeaderboardEntry[]
+    timeExpired?: boolean
+    tags?: TagStats[]
+  }
 }
 
 type ManagerExtraStatus = {
-  SHOW_ROOM: { text: string; inviteCode?: string }
+  SHOW_ROOM: {
+    text: string
+    inviteCode?: string
+    lobbyOrder?: LobbyOrder
+    players?: Player[]
+  }
   SHOW_RESPONSES: {
     question: string
-    responses: Record<number, number>
-    correct: number
+    responses: number[]
+    correct: number[]
     answers: string[]
     image?: string
     video?: string
+    answerers?: string[]
+    numeric?: {
+      target: number
+      tolerance: number
+      summary: NumericSummary | null
+    }
+  }
+  SHOW_LEADERBOARD: {
+    oldLeaderboard: LeaderboardEntry[]
+    leaderboard: LeaderboardEntry[]
+    mode: LeaderboardMode
+    // Ids of the muted players, so the host can unmute them from here
+    muted: string[]
   }
-  SHOW_LEADERBOARD: { oldLeaderboard: Player[]; leaderboard: Player[] }
 }
 
 export type PlayerStatusDataMap = CommonStatusDataMap
@@ -5,4 +5,9 @@ export const usernameValidator = z
   .min(4, "Username cannot be less than 4 characters")
   .max(20, "Username cannot exceed 20 characters")
 
-export const inviteCodeValidator = z.string().length(6, "Invalid invite code")
+export const inviteCodeValidator = z
+  .string()
+  .trim()
+  .toUpperCase()
+  .length(6, "Invalid code format")
+  .regex(/^[A-Z0-9]+$/u, "Invalid code format")
@@ -0,0 +1,176 @@
+import { MAX_ANSWER_OPTIONS } from "@rahoot/common/types/game"
+import z from "zod"
+
+const PIN_POSITIONS = ["first", "last"] as const
+
+const QUESTION_TYPES = ["truefalse", "numeric"] as const
+
+const mediaInfoValidator = z.object({
+  type: z.string().optional(),
+  width: z.number().positive().optional(),
+  height: z.number().positive().optional(),
+  duration: z.number().positive().optional(),
+})
+
+export const questionValidator = z
+  .object({
+    question: z.string().min(1, "Question text cannot be empty"),
+    type: z.enum(QUESTION_TYPES).optional(),
+    section: z.string().optional(),
+    pinned: z.enum(PIN_POSITIONS).optional(),
+    tags: z.array(z.string().min(1, "Tags cannot be empty")).optional(),
+    image: z.string().optional(),
+    video: z.string().optional(),
+    audio: z.string().optional(),
+    media: z
+      .object({
+        image: mediaInfoValidator.optional(),
+        video: mediaInfoValidator.optional(),
+        audio: mediaInfoValidator.optional(),
+      })
+      .optional(),
+    wager: z.boolean().optional(),
+    answers: z.array(z.string()),
+    solution: z.union([
+      z.number().int("Solution must be an answer index"),
+      z.array(z.number().int("Solution must be an answer index")),
+    ]),
+    target: z.number().optional(),
+    tolerance: z.number().nonnegative("Tolerance can't be negative").optional(),
+    minSelect: z.number().int().positive().optional(),
+    maxSelect: z.number().int().positive().optional(),
+    cooldown: z.number().positive("Cooldown must be greater than 0"),
+    time: z.number().positive("Time must be greater than 0"),
+    maxPoints: z
+      .number()
+      .int("Max points must be a whole number")
+      .positive("Max points must be greater than 0")
+      .optional(),
+  })
+  .superRefine((question, ctx) => {
+    // Valid shortcuts are expanded before validation and lose their type
+    if (question.type === "truefalse") {
+      ctx.addIssue({
+        code: "custom",
+        path: ["answers"],
+        message:
+          "A truefalse question takes a true or false solution and no answers",
+      })
+    }
+
+    const total = question.answers.length
+    const solutions = [question.solution].flat()
+
+    // Numeric questions are expanded with no answers and no solution
+    if (question.type === "numeric") {
+      if (question.target === undefined) {
+        ctx.addIssue({
+          code: "custom",
+          path: ["target"],
+          message: "A numeric question needs a target",
+        })
+      }
+
+      if (total > 0 || solutions.length > 0) {
+        ctx.addIssue({
+          code: "custom",
+          path: ["answers"],
+          message: "A numeric question takes a target instead of answers",
+        })
+      }
+
+      return
+    }
+
+    if (total === 0) {
+      ctx.addIssue({
+        code: "custom",
+        path: ["answers"],
+        message: "Question must have at least one answer",
+      })
+    }
+
+    if (total > MAX_ANSWER_OPTIONS) {
+      ctx.addIssue({
+        code: "custom",
+        path: ["answers"],
+        message: `Question has ${total} answers, at most ${MAX_ANSWER_OPTIONS} are allowed`,
+      })
+    }
+
+    if (solutions.length === 0) {
+      ctx.addIssue({
+        code: "custom",
+        path: ["solution"],
+        message: "Solution must list at least one answer",
+      })
+    }
+
+    solutions.forEach((solution) => {
+      if (solution < 0 || solution >= total) {
+        ctx.addIssue({
+          code: "custom",
+          path: ["solution"],
+          message: `Solution ${solution} is out of range (${total} answers)`,
+        })
+      }
+    })
+
+    const { minSelect, maxSelect } = question
+
+    if (minSelect === undefined && maxSelect === undefined) {
+      return
+    }
+
+    if (!Array.isArray(question.solution)) {
+      ctx.addIssue({
+        code: "custom",
+        path: ["minSelect"],
+        message: "minSelect and maxSelect need an array of solutions",
+      })
+
+      return
+    }
+
+    const min = minSelect ?? 1
+    const max = maxSelect ?? total
+
+    if (min > max || max > total) {
+      ctx.addIssue({
+        code: "custom",
+        path: ["maxSelect"],
+        message: `Selection bounds ${min}-${max} don't fit ${total} answers`,
+      })
+    } else if (solutions.length < min || solutions.length > max) {
+      ctx.addIssue({
+        code: "custom",
+        path: ["solution"],
+        message: `${solutions.length} solutions can't be picked within ${min}-${max} answers`,
+      })
+    }
+  })
+
+export const quizzValidator = z
+  .object({
+    subject: z.string().min(1, "Subject cannot be empty"),
+    inviteCode: z.string().optional(),
+    shuffleQuestions: z.boolean().optional(),
+    allowAnswerChange: z.boolean().optional(),
+    warmup: questionValidator.optional(),
+    questions: z
+      .array(questionValidator)
+      .min(1, "Quizz must have at least one question"),
+  })
+  .superRefine((quizz, ctx) => {
+    PIN_POSITIONS.forEach((position) => {
+      const pinned = quizz.questions.filter((q) => q.pinned === position)
+
+      if (pinned.length > 1) {
+        ctx.addIssue({
+          code: "custom",
+          path: ["questions"],
+          message: `Only one question can be pinned ${position}, found ${pinned.length}`,
+        })
+      }
+    })
+  })
@@ -1,6 +1,25 @@
+import { execSync } from "child_process"
 import esbuild from "esbuild"
+import fs from "fs"
 import path from "path"
 
+const pkg = JSON.parse(fs.readFileSync("package.json", "utf-8"))
+
+const getGitCommit = () => {
+  try {
+    return execSync("git rev-parse --short HEAD").toString().trim()
+  } catch {
+    return ""
+  }
+}
+
+// Defaults baked into the bundle but assigned at startup, so APP_VERSION
+// and BUILD_COMMIT set in the environment still take precedence
+const buildEnv = {
+  APP_VERSION: pkg.version,
+  BUILD_COMMIT: getGitCommit(),
+}
+
 export const config = {
   entryPoints: ["src/index.ts"],
   bundle: true,
@@ -11,6 +30,13 @@ export const config = {
   define: {
     "process.env.NODE_ENV": '"production"',
   },
+  banner: {
+    js: Object.entries(buildEnv)
+      .map(
+        ([name, value]) => `process.env.${name} ??= ${JSON.stringify(value)}`,
+      )
+      .join(";"),
+  },
   alias: {
     "@": path.resolve("./src"),
   },
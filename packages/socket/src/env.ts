@@ -5,11 +5,48 @@ const env = createEnv({
   server: {
     WEB_ORIGIN: z.string().optional().default("http://localhost:3000"),
     SOCKER_PORT: z.string().optional().default("3001"),
+    IDLE_TIMEOUT: z.string().optional().default("300"),
+    HEARTBEAT_INTERVAL: z.string().optional().default("20"),
+    HEARTBEAT_TIMEOUT: z.string().optional().default("25"),
+    BASE_PATH: z
+      .string()
+      .optional()
+      .default("")
+      .transform((path) => path.replace(/\/+$/u, "")),
+    WS_PATH: z
+      .string()
+      .regex(/^\//u, "WS_PATH must start with /")
+      .optional()
+      .default("/socket.io"),
+    ACCESS_LOG_LEVEL: z
+      .enum(["none", "info", "debug"])
+      .optional()
+      .default("info"),
+    APP_VERSION: z.string().optional().default("unknown"),
+    GIT_COMMIT: z.string().optional(),
+    QUIZ_SOURCE_URL: z.string().optional(),
+    MAX_MESSAGE_SIZE: z.string().optional().default("16384"),
+    RECONNECT_TOKEN_TTL: z.string().optional().default("43200"),
+    MEDIA_PATH: z.string().optional(),
+    SCORER_MODULE: z.string().optional(),
   },
 
   runtimeEnv: {
     WEB_ORIGIN: process.env.WEB_ORIGIN,
     SOCKER_PORT: process.env.SOCKER_PORT,
+    IDLE_TIMEOUT: process.env.IDLE_TIMEOUT,
+    HEARTBEAT_INTERVAL: process.env.HEARTBEAT_INTERVAL,
+    HEARTBEAT_TIMEOUT: process.env.HEARTBEAT_TIMEOUT,
+    BASE_PATH: process.env.BASE_PATH,
+    WS_PATH: process.env.WS_PATH,
+    ACCESS_LOG_LEVEL: process.env.ACCESS_LOG_LEVEL,
+    APP_VERSION: process.env.APP_VERSION || process.env.npm_package_version,
+    GIT_COMMIT: process.env.GIT_COMMIT || process.env.BUILD_COMMIT,
+    QUIZ_SOURCE_URL: process.env.QUIZ_SOURCE_URL,
+    MAX_MESSAGE_SIZE: process.env.MAX_MESSAGE_SIZE,
+    RECONNECT_TOKEN_TTL: process.env.RECONNECT_TOKEN_TTL,
+    MEDIA_PATH: process.env.MEDIA_PATH,
+    SCORER_MODULE: process.env.SCORER_MODULE,
   },
 })
 
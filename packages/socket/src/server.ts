@@ -0,0 +1,450 @@
+import { Server, Socket } from "@rahoot/common/types/game/socket"
+import { inviteCodeValidator } from "@rahoot/common/validators/auth"
+import env from "@rahoot/socket/env"
+import Config from "@rahoot/socket/services/config"
+import Game from "@rahoot/socket/services/game"
+import { handleRequest } from "@rahoot/socket/services/http"
+import Registry from "@rahoot/socket/services/registry"
+import {
+  checkManagerPassword,
+  createAttemptLimiter,
+  TOO_MANY_ATTEMPTS,
+} from "@rahoot/socket/utils/attempts"
+import { Clock } from "@rahoot/socket/utils/clock"
+import { withGame } from "@rahoot/socket/utils/game"
+import { getHosts, joinAsCoHost } from "@rahoot/socket/utils/host"
+import {
+  adjustScore,
+  sendStandings,
+  showLeaderboard,
+} from "@rahoot/socket/utils/leaderboard"
+import { applyLifeline } from "@rahoot/socket/utils/lifeline"
+import {
+  broadcastLobby,
+  joinGame,
+  kickPlayer,
+  messagePlayer,
+  mutePlayer,
+} from "@rahoot/socket/utils/lobby"
+import { logAccess, logUpgrade } from "@rahoot/socket/utils/log"
+import { playerDisconnected } from "@rahoot/socket/utils/pause"
+import {
+  createRandom,
+  normalizeSeed,
+  randomSeed,
+} from "@rahoot/socket/utils/random"
+import { redoQuestion } from "@rahoot/socket/utils/redo"
+import { rematchGame } from "@rahoot/socket/utils/rematch"
+import { eliminateAnswer } from "@rahoot/socket/utils/reveal"
+import { selectAnswer } from "@rahoot/socket/utils/selection"
+import {
+  guardMessages,
+  recordCommands,
+  watchIdleSocket,
+} from "@rahoot/socket/utils/socket"
+import { verifyReconnectToken } from "@rahoot/socket/utils/token"
+import { getServerInfo } from "@rahoot/socket/utils/version"
+import { setWager } from "@rahoot/socket/utils/wager"
+import { createServer } from "http"
+import { Server as ServerIO } from "socket.io"
+
+const registry = Registry.getInstance()
+const inviteAttempts = createAttemptLimiter()
+
+export const createGame = (
+  io: Server,
+  socket: Socket,
+  quizzId: string,
+  options: { inviteCode?: string; seed?: number; clock?: Clock } = {},
+) => {
+  const quizz = Config.quizz().find((q) => q.id === quizzId)
+
+  if (!quizz) {
+    socket.emit("game:errorMessage", "Quizz not found")
+
+    return null
+  }
+
+  const desiredCode = options.inviteCode || quizz.inviteCode
+  const result = desiredCode ? inviteCodeValidator.safeParse(desiredCode) : null
+
+  if (result?.error) {
+    socket.emit("manager:errorMessage", result.error.issues[0].message)
+
+    return null
+  }
+
+  const game = new Game(io, socket, quizz, {
+    inviteCode: result?.data,
+    seed: options.seed,
+    clock: options.clock,
+  })
+  registry.addGame(game)
+
+  return game
+}
+
+export const createRandomGame = (
+  io: Server,
+  socket: Socket,
+  options: {
+    inviteCode?: string
+    tag?: string
+    seed?: number
+    clock?: Clock
+  } = {},
+) => {
+  const { tag, ...gameOptions } = options
+  const quizzList = Config.quizz().filter(
+    (quizz) =>
+      !tag || quizz.questions.some((question) => question.tags?.includes(tag)),
+  )
+
+  if (quizzList.length === 0) {
+    socket.emit(
+      "manager:errorMessage",
+      tag ? `No quizz with the tag ${tag}` : "No quizz available",
+    )
+
+    return null
+  }
+
+  // The game gets the same seed, so it also reproduces the pick
+  const seed = normalizeSeed(options.seed) ?? randomSeed()
+  const random = createRandom(seed)
+  const quizz = quizzList[Math.floor(random() * quizzList.length)]
+
+  return createGame(io, socket, quizz.id, { ...gameOptions, seed })
+}
+
+const handleManagerDisconnect = (game: Game, socketId: string) => {
+  const hosts = getHosts(game)
+
+  game.eventLog?.record("info", "managerDisconnected", { target: socketId })
+
+  hosts
+    .filter((host) => host.id === socketId)
+    .forEach((host) => {
+      host.connected = false
+    })
+
+  // The game goes on as long as the manager or a co-host is still there
+  if (hosts.some((host) => host.connected)) {
+    return
+  }
+
+  registry.markGameAsEmpty(game)
+
+  const isPending = !game.started && !game.finished
+
+  if (isPending || !game.config.allowReconnect) {
+    console.log("Reset game (manager disconnected)")
+    game.started = false
+    game.abortCooldown()
+    game.io.to(game.gameId).emit("game:reset", {
+      gameId: game.gameId,
+      message: "Manager disconnected",
+    })
+    registry.removeGame(game.gameId)
+  }
+}
+
+const handlePlayerDisconnect = (game: Game, socketId: string) => {
+  const player = game.players.find((p) => p.id === socketId)
+
+  if (!player) {
+    return
+  }
+
+  game.eventLog?.record("info", "playerDisconnected", { target: socketId })
+
+  if (!game.started && !game.finished) {
+    game.players = game.players.filter((p) => p.id !== socketId)
+
+    game.io.to(game.managerRoom).emit("manager:removePlayer", player.id)
+    broadcastLobby(game)
+
+    console.log(`Removed player ${player.username} from game ${game.gameId}`)
+
+    return
+  }
+
+  playerDisconnected(game, player)
+}
+
+// Messages are handled as they arrive, only the answer count may be dropped
+const handleConnection = (io: Server, socket: Socket) => {
+  console.log(
+    `A user connected: socketId: ${socket.id}, clientId: ${socket.handshake.auth.clientId}`,
+  )
+
+  watchIdleSocket(socket)
+  guardMessages(socket)
+  recordCommands(socket)
+  socket.emit("game:serverInfo", getServerInfo())
+
+  socket.on("game:ping", (nonce) => {
+    if (typeof nonce !== "string" || nonce.length > 64) {
+      return
+    }
+
+    socket.emit("game:pong", nonce)
+  })
+
+  socket.on("game:reconnect", (token) => {
+    const claims = verifyReconnectToken(token)
+    const { clientId } = socket.handshake.auth
+
+    if (!claims || claims.clientId !== clientId) {
+      socket.emit("game:reset", {
+        gameId: claims?.gameId,
+        message: "Session expired, please join again",
+      })
+
+      return
+    }
+
+    const game =
+      registry.getManagerGame(claims.gameId, clientId) ||
+      registry.getPlayerGame(claims.gameId, clientId)
+
+    if (!game) {
+      socket.emit("game:reset", {
+        gameId: claims.gameId,
+        message: "Game not found",
+      })
+
+      return
+    }
+
+    if (!game.config.allowReconnect) {
+      socket.emit("game:reset", {
+        gameId: game.gameId,
+        message: "Reconnection is disabled for this game",
+      })
+
+      return
+    }
+
+    game.reconnect(socket)
+  })
+
+  socket.on("manager:auth", (password) => {
+    try {
+      const check = checkManagerPassword(socket.handshake.address, password)
+
+      if (!check.valid) {
+        socket.emit("manager:errorMessage", check.error)
+
+        return
+      }
+
+      socket.emit("manager:quizzList", Config.quizz())
+    } catch (error) {
+      console.error("Failed to read game config:", error)
+      socket.emit("manager:errorMessage", "Failed to read game config")
+    }
+  })
+
+  socket.on("game:create", (quizzId, inviteCode, seed) => {
+    createGame(io, socket, quizzId, { inviteCode, seed })
+  })
+
+  socket.on("game:createRandom", (inviteCode, tag) => {
+    createRandomGame(io, socket, { inviteCode, tag })
+  })
+
+  socket.on("player:join", (inviteCode) => {
+    // Players behind one NAT or proxy share an address
+    const { address, auth } = socket.handshake
+    const key = `${address}:${auth.clientId}`
+
+    if (inviteAttempts.isBlocked(key)) {
+      socket.emit("game:errorMessage", TOO_MANY_ATTEMPTS)
+
+      return
+    }
+
+    const result = inviteCodeValidator.safeParse(inviteCode)
+
+    if (result.error) {
+      socket.emit(
+        "game:errorMessage",
+        inviteAttempts.fail(key, result.error.issues[0].message),
+      )
+
+      return
+    }
+
+    const game = registry.getGameByInviteCode(result.data)
+
+    if (!game) {
+      socket.emit(
+        "game:errorMessage",
+        inviteAttempts.fail(
+          key,
+          registry.isInviteCodeEnded(result.data)
+            ? "Game has ended"
+            : "No game with that code",
+        ),
+      )
+
+      return
+    }
+
+    if (game.finished) {
+      socket.emit("game:errorMessage", "Game has ended")
+
+      return
+    }
+
+    inviteAttempts.reset(key)
+    socket.emit("game:successRoom", game.gameId)
+  })
+
+  socket.on("player:login", ({ gameId, data }) =>
+    withGame(gameId, socket, (game) =>
+      joinGame(game, socket, data.username, data.locale),
+    ),
+  )
+
+  socket.on("manager:joinAsCoHost", ({ gameId, password }) => {
+    const game = registry.getGameById(gameId)
+
+    if (!game) {
+      socket.emit("manager:errorMessage", "Game not found")
+
+      return
+    }
+
+    joinAsCoHost(game, socket, password)
+  })
+
+  socket.on("manager:kickPlayer", ({ gameId, playerId }) =>
+    withGame(gameId, socket, (game) => kickPlayer(game, socket, playerId)),
+  )
+
+  socket.on("manager:mutePlayer", ({ gameId, playerId }) =>
+    withGame(gameId, socket, (game) =>
+      mutePlayer(game, socket, playerId, true),
+    ),
+  )
+
+  socket.on("manager:unmutePlayer", ({ gameId, playerId }) =>
+    withGame(gameId, socket, (game) =>
+      mutePlayer(game, socket, playerId, false),
+    ),
+  )
+
+  socket.on("manager:messagePlayer", ({ gameId, playerId, message }) =>
+    withGame(gameId, socket, (game) =>
+      messagePlayer(game, socket, playerId, message),
+    ),
+  )
+
+  socket.on("manager:adjustScore", ({ gameId, playerId, delta }) =>
+    withGame(gameId, socket, (game) =>
+      adjustScore(game, socket, playerId, delta),
+    ),
+  )
+
+  socket.on("manager:startGame", ({ gameId }) =>
+    withGame(gameId, socket, (game) => game.start(socket)),
+  )
+
+  socket.on("player:selectedAnswer", ({ gameId, data }) =>
+    withGame(gameId, socket, (game) =>
+      selectAnswer(game, socket, {
+        answerIds: data.answerKeys || [data.answerKey],
+        confidence: data.confidence,
+        questionIndex: data.questionIndex,
+      }),
+    ),
+  )
+
+  socket.on("player:submitNumeric", ({ gameId, data }) =>
+    withGame(gameId, socket, (game) =>
+      selectAnswer(game, socket, {
+        answerIds: [],
+        value: data.value,
+        confidence: data.confidence,
+        questionIndex: data.questionIndex,
+      }),
+    ),
+  )
+
+  socket.on("player:setWager", ({ gameId, data }) =>
+    withGame(gameId, socket, (game) => setWager(game, socket, data.amount)),
+  )
+
+  socket.on("player:useLifeline", ({ gameId, data }) =>
+    withGame(gameId, socket, (game) => applyLifeline(game, socket, data.kind)),
+  )
+
+  socket.on("player:requestStandings", ({ gameId }) =>
+    withGame(gameId, socket, (game) => sendStandings(game, socket)),
+  )
+
+  socket.on("manager:abortQuiz", ({ gameId }) =>
+    withGame(gameId, socket, (game) => game.abortRound(socket)),
+  )
+
+  socket.on("manager:nextQuestion", ({ gameId }) =>
+    withGame(gameId, socket, (game) => game.nextRound(socket)),
+  )
+
+  socket.on("manager:showLeaderboard", ({ gameId }) =>
+    withGame(gameId, socket, (game) => showLeaderboard(game, socket)),
+  )
+
+  socket.on("manager:eliminateAnswer", ({ gameId }) =>
+    withGame(gameId, socket, (game) => eliminateAnswer(game, socket)),
+  )
+
+  socket.on("manager:redoQuestion", ({ gameId }) =>
+    withGame(gameId, socket, (game) => redoQuestion(game, socket)),
+  )
+
+  socket.on("manager:rematch", ({ gameId, keepPlayers }) =>
+    withGame(gameId, socket, (game) => rematchGame(game, socket, keepPlayers)),
+  )
+
+  socket.on("disconnect", () => {
+    console.log(`A user disconnected : ${socket.id}`)
+
+    registry
+      .getGamesByManagerSocketId(socket.id)
+      .forEach((game) => handleManagerDisconnect(game, socket.id))
+
+    registry
+      .getGamesByPlayerSocketId(socket.id)
+      .forEach((game) => handlePlayerDisconnect(game, socket.id))
+  })
+}
+
+export const attachGameHandlers = (io: Server) => {
+  io.on("connection", (socket) => handleConnection(io, socket))
+}
+
+export const createRahootServer = () => {
+  const httpServer = createServer(handleRequest)
+  const io: Server = new ServerIO(httpServer, {
+    path: `${env.BASE_PATH}${env.WS_PATH}`,
+    maxHttpBufferSize: Number(env.MAX_MESSAGE_SIZE),
+    // Dropped mobile connections are closed, and their players disconnected,
+    // once a ping goes unanswered instead of when TCP gives up
+    pingInterval: Number(env.HEARTBEAT_INTERVAL) * 1000,
+    pingTimeout: Number(env.HEARTBEAT_TIMEOUT) * 1000,
+    cors: {
+      origin: [env.WEB_ORIGIN],
+    },
+  })
+
+  httpServer.on("request", logAccess)
+  httpServer.on("upgrade", logUpgrade)
+  attachGameHandlers(io)
+
+  return { httpServer, io }
+}
+
+export { Config, Game }
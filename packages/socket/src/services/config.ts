@@ -1,15 +1,165 @@
-import { QuizzWithId } from "@rahoot/common/types/game"
+import {
+  GameConfig,
+  Question,
+  Quizz,
+  QuizzWithId,
+} from "@rahoot/common/types/game"
+import env from "@rahoot/socket/env"
+import { sanitizeMarkup } from "@rahoot/socket/utils/markup"
+import { findMissingMedia, resolveMedia } from "@rahoot/socket/utils/media"
+import { expandQuizz, validateQuizz } from "@rahoot/socket/utils/quizz"
 import fs from "fs"
 import { resolve } from "path"
 
-const inContainerPath = process.env.CONFIG_PATH
+const DEFAULT_MANAGER_PASSWORD = "PASSWORD"
 
+const GAME_CONFIG_DEFAULTS: GameConfig = {
+  music: true,
+  hidePoints: false,
+  showManagerLeaderboard: false,
+  showAnswererNames: false,
+  hideAnswerTiming: false,
+  hideAnswersUntilLock: true,
+  showQuestionOnDevices: true,
+  managerTeleprompter: false,
+  minAnswerTime: 0,
+  allAnsweredGrace: 0,
+  answerCountInterval: 250,
+  pacing: {
+    autoStart: 0,
+    autoLeaderboard: 0,
+    autoNextQuestion: 0,
+    managerOverride: true,
+  },
+  gameReview: false,
+  maxGameDuration: 0,
+  allowReconnect: true,
+  reconnectGrace: 5,
+  recordEvents: false,
+  anonymizeExports: false,
+  pauseOnDisconnect: false,
+  pauseTimeout: 30,
+  allDisconnected: "continue",
+  allDisconnectedTimeout: 60,
+  capacityWarnings: [80, 100],
+  lobbyOrder: "join",
+  showLobbyPlayers: false,
+  rematchKeepPlayers: true,
+  feedbackMessages: {
+    correct: ["Nice!"],
+    wrong: ["Too bad"],
+  },
+  askConfidence: false,
+  confidenceMultipliers: {
+    low: { correct: 0.5, wrong: 0 },
+    medium: { correct: 1, wrong: 0.25 },
+    high: { correct: 1.5, wrong: 0.5 },
+  },
+  pointsDisplay: "total",
+  pointsFormat: "raw",
+  allowNegativePoints: false,
+  quizzMarkup: "escape",
+  leaderboardMode: "points",
+  stagedReveal: false,
+  lifelines: {
+    fiftyFifty: 0,
+    extraTime: 0,
+  },
+  extraTimeSeconds: 10,
+  timings: {
+    start: 3,
+    startCountdown: 3,
+    prepared: 2,
+  },
+  scoring: {
+    mode: "linear",
+    fastWindow: 2,
+    decay: "linear",
+  },
+  scorer: "default",
+  staleAnswer: "reset",
+}
+
+// Quizzes are read again on every request, a quizz's problems are only
+// logged again once its content changes
+const reportedQuizz = new Map<string, string>()
+
+const reportOnce = (id: string, content: string, report: () => void) => {
+  if (reportedQuizz.get(id) === content) {
+    return
+  }
+
+  reportedQuizz.set(id, content)
+  report()
+}
+
+const checkQuizz = (id: string, data: unknown): QuizzWithId[] => {
+  const { errors, warnings } = validateQuizz(data)
+
+  reportOnce(id, JSON.stringify(data), () => {
+    warnings.forEach((warning) => {
+      console.warn(`Quizz ${id}: ${warning}`)
+    })
+
+    if (errors.length > 0) {
+      console.error(`Quizz ${id} is invalid:\n- ${errors.join("\n- ")}`)
+    }
+  })
+
+  if (errors.length > 0) {
+    return []
+  }
+
+  const quizz = expandQuizz(data) as Quizz
+  const { quizzMarkup } = Config.game()
+
+  const prepareQuestion = (question: Question) => ({
+    ...question,
+    question: sanitizeMarkup(question.question, quizzMarkup),
+    answers: question.answers.map((answer) =>
+      sanitizeMarkup(answer, quizzMarkup),
+    ),
+    media: resolveMedia(question),
+  })
+
+  return [
+    {
+      ...quizz,
+      id,
+      warmup: quizz.warmup && prepareQuestion(quizz.warmup),
+      questions: quizz.questions.map(prepareQuestion),
+    },
+  ]
+}
+
+// Read on each call so tests can point it to their own folder
 const getPath = (path: string = "") =>
-  inContainerPath
-    ? resolve(inContainerPath, path)
+  process.env.CONFIG_PATH
+    ? resolve(process.env.CONFIG_PATH, path)
     : resolve(process.cwd(), "../../config", path)
 
+// A file that can't be parsed is skipped without hiding the other quizzes
+const readQuizzFile = (file: string) => {
+  const id = file.replace(".json", "")
+  const data = fs.readFileSync(getPath(`quizz/${file}`), "utf-8")
+  let parsed: unknown
+
+  try {
+    parsed = JSON.parse(data)
+  } catch (error) {
+    reportOnce(id, data, () => {
+      console.error(`Quizz ${id} is not valid JSON:`, error)
+    })
+
+    return []
+  }
+
+  return checkQuizz(id, parsed)
+}
+
 class Config {
+  private static remoteQuizz: QuizzWithId[] = []
+
   static init() {
     const isConfigFolderExists = fs.existsSync(getPath())
 
@@ -24,7 +174,7 @@ class Config {
         getPath("game.json"),
         JSON.stringify(
           {
-            managerPassword: "PASSWORD",
+            managerPassword: DEFAULT_MANAGER_PASSWORD,
             music: true,
           },
           null,
@@ -76,7 +226,7 @@ class Config {
     }
   }
 
-  static game() {
+  static game(): GameConfig {
     const isExists = fs.existsSync(getPath("game.json"))
 
     if (!isExists) {
@@ -84,14 +234,53 @@ class Config {
     }
 
     try {
-      const config = fs.readFileSync(getPath("game.json"), "utf-8")
+      // autoLeaderboard used to be a top level option, now part of pacing
+      const { autoLeaderboard, ...config } = JSON.parse(
+        fs.readFileSync(getPath("game.json"), "utf-8"),
+      )
+      const multipliers = GAME_CONFIG_DEFAULTS.confidenceMultipliers
 
-      return JSON.parse(config)
+      return {
+        ...GAME_CONFIG_DEFAULTS,
+        ...config,
+        // Merged per level, so overriding only the wrong answer factor of a
+        // level keeps its default factor for correct answers
+        confidenceMultipliers: {
+          low: { ...multipliers.low, ...config.confidenceMultipliers?.low },
+          medium: {
+            ...multipliers.medium,
+            ...config.confidenceMultipliers?.medium,
+          },
+          high: { ...multipliers.high, ...config.confidenceMultipliers?.high },
+        },
+        lifelines: {
+          ...GAME_CONFIG_DEFAULTS.lifelines,
+          ...config.lifelines,
+        },
+        feedbackMessages: {
+          ...GAME_CONFIG_DEFAULTS.feedbackMessages,
+          ...config.feedbackMessages,
+        },
+        timings: {
+          ...GAME_CONFIG_DEFAULTS.timings,
+          ...config.timings,
+        },
+        scoring: {
+          ...GAME_CONFIG_DEFAULTS.scoring,
+          ...config.scoring,
+        },
+        pacing: {
+          ...GAME_CONFIG_DEFAULTS.pacing,
+          autoLeaderboard:
+            autoLeaderboard ?? GAME_CONFIG_DEFAULTS.pacing.autoLeaderboard,
+          ...config.pacing,
+        },
+      }
     } catch (error) {
       console.error("Failed to read game config:", error)
     }
 
-    return {}
+    return { ...GAME_CONFIG_DEFAULTS }
   }
 
   static quizz() {
@@ -106,23 +295,111 @@ class Config {
         .readdirSync(getPath("quizz"))
         .filter((file) => file.endsWith(".json"))
 
-      const quizz: QuizzWithId[] = files.map((file) => {
-        const data = fs.readFileSync(getPath(`quizz/${file}`), "utf-8")
-        const config = JSON.parse(data)
+      const quizz = files.flatMap(readQuizzFile)
+
+      return [...quizz, ...Config.remoteQuizz]
+    } catch (error) {
+      console.error("Failed to read quizz config:", error)
+
+      return [...Config.remoteQuizz]
+    }
+  }
+
+  // Run once at startup, the quizzes are read again on every request
+  static checkMedia() {
+    const mediaPath = env.MEDIA_PATH
+
+    if (!mediaPath) {
+      return
+    }
 
-        const id = file.replace(".json", "")
+    Config.quizz().forEach((quizz) => {
+      const questions = quizz.warmup
+        ? [quizz.warmup, ...quizz.questions]
+        : quizz.questions
 
-        return {
-          id,
-          ...config,
-        }
+      questions.forEach((question) => {
+        findMissingMedia(question, mediaPath).forEach((url) => {
+          console.warn(
+            `Quizz ${quizz.id}: media ${url} not found in ${mediaPath}`,
+          )
+        })
       })
+    })
+  }
 
-      return quizz || []
-    } catch (error) {
-      console.error("Failed to read quizz config:", error)
+  static hasQuizz(id: string) {
+    return fs.existsSync(getPath(`quizz/${id}.json`))
+  }
 
-      return []
+  static readQuizz(id: string): Quizz | null {
+    if (!Config.hasQuizz(id)) {
+      return null
+    }
+
+    return JSON.parse(fs.readFileSync(getPath(`quizz/${id}.json`), "utf-8"))
+  }
+
+  static saveQuizz(id: string, quizz: Quizz) {
+    fs.writeFileSync(
+      getPath(`quizz/${id}.json`),
+      JSON.stringify(quizz, null, 2),
+    )
+  }
+
+  static resolved() {
+    const { managerPassword, ...game } = Config.game()
+
+    return {
+      configPath: getPath(),
+      basePath: env.BASE_PATH,
+      wsPath: env.WS_PATH,
+      webOrigin: env.WEB_ORIGIN,
+      idleTimeout: Number(env.IDLE_TIMEOUT),
+      heartbeatInterval: Number(env.HEARTBEAT_INTERVAL),
+      heartbeatTimeout: Number(env.HEARTBEAT_TIMEOUT),
+      accessLogLevel: env.ACCESS_LOG_LEVEL,
+      maxMessageSize: Number(env.MAX_MESSAGE_SIZE),
+      reconnectTokenTtl: Number(env.RECONNECT_TOKEN_TTL),
+      quizSourceUrl: env.QUIZ_SOURCE_URL || null,
+      mediaPath: env.MEDIA_PATH || null,
+      quizzCount: Config.quizz().length,
+      managerPassword: managerPassword ? "[redacted]" : null,
+      defaultPassword: managerPassword === DEFAULT_MANAGER_PASSWORD,
+      game,
+    }
+  }
+
+  static async loadRemoteQuizz() {
+    if (!env.QUIZ_SOURCE_URL) {
+      return
+    }
+
+    try {
+      const response = await fetch(env.QUIZ_SOURCE_URL)
+
+      if (!response.ok) {
+        throw new Error(`Unexpected response status ${response.status}`)
+      }
+
+      const manifest = await response.json()
+
+      if (!Array.isArray(manifest)) {
+        throw new Error("Manifest must be an array of quizzes")
+      }
+
+      Config.remoteQuizz = manifest.flatMap((data, index) =>
+        checkQuizz(`remote-${data?.id ?? index}`, data),
+      )
+
+      console.log(
+        `Loaded ${Config.remoteQuizz.length} remote quizz(es) from ${env.QUIZ_SOURCE_URL}`,
+      )
+    } catch (error) {
+      console.error(
+        "Failed to load remote quizzes, using local ones only:",
+        error,
+      )
     }
   }
 }
@@ -0,0 +1,100 @@
+import { Server } from "@rahoot/common/types/game/socket"
+import { Clock, systemClock } from "@rahoot/socket/utils/clock"
+
+class Cooldown {
+  io: Server
+  room: string
+  active: boolean
+  runs: number
+  paused: boolean
+  pausedAt: number
+  clock: Clock
+
+  constructor(io: Server, room: string, clock: Clock = systemClock) {
+    this.io = io
+    this.room = room
+    this.clock = clock
+    this.active = false
+    this.runs = 0
+    this.paused = false
+    this.pausedAt = 0
+  }
+
+  start(
+    seconds: number,
+    target: string | string[] = this.room,
+  ): Promise<boolean> {
+    if (this.active) {
+      return Promise.resolve(false)
+    }
+
+    this.active = true
+    this.runs += 1
+    let count = seconds - 1
+
+    return new Promise<boolean>((resolve) => {
+      const cooldownTimeout = setInterval(() => {
+        if (this.active && this.paused) {
+          return
+        }
+
+        if (!this.active || count <= 0) {
+          const completed = this.active
+
+          this.active = false
+          clearInterval(cooldownTimeout)
+          resolve(completed)
+
+          return
+        }
+
+        this.io.to(target).emit("game:cooldown", { gameId: this.room, count })
+        count -= 1
+      }, 1000)
+    })
+  }
+
+  abort() {
+    this.active &&= false
+    this.paused = false
+  }
+
+  pause() {
+    if (!this.active || this.paused) {
+      return false
+    }
+
+    this.paused = true
+    this.pausedAt = this.clock()
+
+    return true
+  }
+
+  resume() {
+    if (!this.paused) {
+      return 0
+    }
+
+    this.paused = false
+
+    return this.clock() - this.pausedAt
+  }
+
+  abortAfter(seconds: number, shouldAbort: () => boolean) {
+    if (seconds <= 0) {
+      this.abort()
+
+      return
+    }
+
+    const { runs } = this
+
+    setTimeout(() => {
+      if (this.runs === runs && shouldAbort()) {
+        this.abort()
+      }
+    }, seconds * 1000)
+  }
+}
+
+export default Cooldown
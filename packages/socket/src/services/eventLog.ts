@@ -0,0 +1,35 @@
+import { Clock } from "@rahoot/socket/utils/clock"
+
+export type LoggedEvent = {
+  at: number
+  kind: "command" | "status" | "info"
+  event: string
+  target?: string
+  data?: unknown
+}
+
+const MAX_EVENTS = 5000
+
+class EventLog {
+  clock: Clock
+  events: LoggedEvent[] = []
+
+  constructor(clock: Clock) {
+    this.clock = clock
+  }
+
+  record(
+    kind: LoggedEvent["kind"],
+    event: string,
+    details: { target?: string; data?: unknown } = {},
+  ) {
+    this.events.push({ at: this.clock(), kind, event, ...details })
+
+    // Ring buffer, the oldest events go first
+    if (this.events.length > MAX_EVENTS) {
+      this.events.shift()
+    }
+  }
+}
+
+export default EventLog
@@ -1,67 +1,135 @@
-import { Answer, Player, Quizz } from "@rahoot/common/types/game"
+import {
+  Answer,
+  GameConfig,
+  Player,
+  Question,
+  QuestionStats,
+  Quizz,
+} from "@rahoot/common/types/game"
 import { Server, Socket } from "@rahoot/common/types/game/socket"
 import { Status, STATUS, StatusDataMap } from "@rahoot/common/types/game/status"
-import { usernameValidator } from "@rahoot/common/validators/auth"
-import Registry from "@rahoot/socket/services/registry"
-import { createInviteCode, timeToPoint } from "@rahoot/socket/utils/game"
+import Config from "@rahoot/socket/services/config"
+import Cooldown from "@rahoot/socket/services/cooldown"
+import EventLog from "@rahoot/socket/services/eventLog"
+import StatusStore from "@rahoot/socket/services/status"
+import {
+  getSelectBounds,
+  getValidKeys,
+  isMultiSelect,
+  isNumeric,
+} from "@rahoot/socket/utils/answers"
+import { Clock, systemClock } from "@rahoot/socket/utils/clock"
+import { isGameExpired, scheduleGameEnd } from "@rahoot/socket/utils/duration"
+import {
+  getQuestionProgress,
+  orderQuestions,
+  resolveInviteCode,
+} from "@rahoot/socket/utils/game"
+import { findHost, Host, isHost } from "@rahoot/socket/utils/host"
+import {
+  finishGame,
+  scheduleLeaderboard,
+} from "@rahoot/socket/utils/leaderboard"
+import { runOvertime, sendLifelines } from "@rahoot/socket/utils/lifeline"
+import { sendFinalAnswerCount } from "@rahoot/socket/utils/lobby"
+import { schedulePacing, takeOverPacing } from "@rahoot/socket/utils/pacing"
+import { waitForResume } from "@rahoot/socket/utils/pause"
+import { broadcastQuestion } from "@rahoot/socket/utils/question"
+import {
+  reconnectManager,
+  reconnectPlayer,
+} from "@rahoot/socket/utils/reconnect"
+import { buildPlayerResult, scorePlayers } from "@rahoot/socket/utils/results"
+import {
+  createRandom,
+  normalizeSeed,
+  Random,
+  randomSeed,
+} from "@rahoot/socket/utils/random"
+import { rememberRound } from "@rahoot/socket/utils/redo"
+import {
+  showResponses,
+  startStagedReveal,
+} from "@rahoot/socket/utils/reveal"
 import sleep from "@rahoot/socket/utils/sleep"
+import { sendManagerPreview } from "@rahoot/socket/utils/teleprompter"
+import { createReconnectToken } from "@rahoot/socket/utils/token"
 import { v4 as uuid } from "uuid"
 
-const registry = Registry.getInstance()
-
 class Game {
   io: Server
 
   gameId: string
-  manager: {
-    id: string
-    clientId: string
-    connected: boolean
-  }
+  manager: Host
+  coHosts: Host[] = []
+  managerRoom: string
   inviteCode: string
+  seed: number
+  random: Random
+  clock: Clock
   started: boolean
+  starting = false
+  roundId = 0
+  startedAt: number | null = null
+  finished: boolean
+  intermission: boolean
 
-  lastBroadcastStatus: { name: Status; data: StatusDataMap[Status] } | null =
-    null
-  managerStatus: { name: Status; data: StatusDataMap[Status] } | null = null
-  playerStatus: Map<string, { name: Status; data: StatusDataMap[Status] }> =
-    new Map()
+  status: StatusStore
+  eventLog: EventLog | null
 
   leaderboard: Player[]
   tempOldLeaderboard: Player[] | null
 
   quizz: Quizz
+  config: GameConfig
   players: Player[]
+  stats: QuestionStats[] = []
 
   round: {
     currentQuestion: number
     playersAnswers: Answer[]
+    wagers: Map<string, number>
+    eliminated: number[] | null
+    extensions: Map<string, number>
+    removedAnswers: Map<string, number[]>
+    overtime: boolean
     startTime: number
   }
 
-  cooldown: {
-    active: boolean
-    ms: number
-  }
+  cooldown: Cooldown
+  unlockTimeout: ReturnType<typeof setTimeout> | null = null
+  pausedBy: Set<string> = new Set()
+  abandonedAt: number | null = null
 
-  constructor(io: Server, socket: Socket, quizz: Quizz) {
+  constructor(
+    io: Server,
+    socket: Socket,
+    quizz: Quizz,
+    options: { inviteCode?: string; seed?: number; clock?: Clock } = {},
+  ) {
     if (!io) {
       throw new Error("Socket server not initialized")
     }
 
     this.io = io
     this.gameId = uuid()
+    this.managerRoom = `${this.gameId}:manager`
     this.manager = {
       id: "",
       clientId: "",
       connected: false,
     }
     this.inviteCode = ""
+    this.seed = normalizeSeed(options.seed) ?? randomSeed()
+    this.random = createRandom(this.seed)
+    this.clock = options.clock ?? systemClock
     this.started = false
+    this.finished = false
+    this.intermission = false
+    this.config = Config.game()
+    this.eventLog = this.config.recordEvents ? new EventLog(this.clock) : null
 
-    this.lastBroadcastStatus = null
-    this.managerStatus = null
-    this.playerStatus = new Map()
+    this.status = new StatusStore(io, this.gameId, this.eventLog)
 
     this.leaderboard = []
     this.tempOldLeaderboard = null
@@ -70,39 +138,43 @@ class Game {
 
     this.round = {
       playersAnswers: [],
+      wagers: new Map(),
+      eliminated: null,
+      extensions: new Map(),
+      removedAnswers: new Map(),
+      overtime: false,
       currentQuestion: 0,
       startTime: 0,
     }
 
-    this.cooldown = {
-      active: false,
-      ms: 0,
-    }
+    this.cooldown = new Cooldown(io, this.gameId, this.clock)
 
-    const roomInvite = createInviteCode()
+    const roomInvite = resolveInviteCode(options.inviteCode)
     this.inviteCode = roomInvite
     this.manager = {
       id: socket.id,
       clientId: socket.handshake.auth.clientId,
       connected: true,
     }
-    this.quizz = quizz
+    this.quizz = { ...quizz, questions: orderQuestions(quizz, this.random) }
 
-    socket.join(this.gameId)
+    socket.join([this.gameId, this.managerRoom])
     socket.emit("manager:gameCreated", {
       gameId: this.gameId,
+      token: createReconnectToken(this.gameId, this.manager.clientId),
       inviteCode: roomInvite,
+      subject: quizz.subject,
+      lobbyOrder: this.config.lobbyOrder,
+      seed: this.seed,
     })
 
     console.log(
-      `New game created: ${roomInvite} subject: ${this.quizz.subject}`,
+      `New game created: ${roomInvite} subject: ${this.quizz.subject} seed: ${this.seed}`,
     )
   }
 
   broadcastStatus<T extends Status>(status: T, data: StatusDataMap[T]) {
-    const statusData = { name: status, data }
-    this.lastBroadcastStatus = statusData
-    this.io.to(this.gameId).emit("game:status", statusData)
+    this.status.broadcast(status, data)
   }
 
   sendStatus<T extends Status>(
@@ -110,392 +182,363 @@ class Game {
     status: T,
     data: StatusDataMap[T],
   ) {
-    const statusData = { name: status, data }
-
-    if (this.manager.id === target) {
-      this.managerStatus = statusData
+    if (target === this.managerRoom) {
+      this.status.sendManager(target, status, data)
     } else {
-      this.playerStatus.set(target, statusData)
+      this.status.sendPlayer(target, status, data)
     }
-
-    this.io.to(target).emit("game:status", statusData)
   }
 
-  join(socket: Socket, username: string) {
-    const isAlreadyConnected = this.players.find(
-      (p) => p.clientId === socket.handshake.auth.clientId,
-    )
-
-    if (isAlreadyConnected) {
-      socket.emit("game:errorMessage", "Player already connected")
-
-      return
-    }
-
-    const result = usernameValidator.safeParse(username)
-
-    if (result.error) {
-      socket.emit("game:errorMessage", result.error.issues[0].message)
-
-      return
-    }
-
-    socket.join(this.gameId)
-
-    const playerData = {
-      id: socket.id,
-      clientId: socket.handshake.auth.clientId,
-      connected: true,
-      username,
-      points: 0,
+  reconnect(socket: Socket) {
+    const { clientId } = socket.handshake.auth
+    if (findHost(this, clientId)) {
+      reconnectManager(this, socket)
+    } else {
+      reconnectPlayer(this, socket)
     }
+  }
 
-    this.players.push(playerData)
-
-    this.io.to(this.manager.id).emit("manager:newPlayer", playerData)
-    this.io.to(this.gameId).emit("game:totalPlayers", this.players.length)
-
-    socket.emit("game:successJoin", this.gameId)
+  startCooldown(seconds: number): Promise<boolean> {
+    return this.cooldown.start(seconds)
   }
 
-  kickPlayer(socket: Socket, playerId: string) {
-    if (this.manager.id !== socket.id) {
-      return
+  // A round ended or redone before the answers unlock must not unlock the
+  // next one early
+  clearUnlockTimeout() {
+    if (this.unlockTimeout) {
+      clearTimeout(this.unlockTimeout)
+      this.unlockTimeout = null
     }
+  }
 
-    const player = this.players.find((p) => p.id === playerId)
+  abortCooldown() {
+    this.cooldown.abort()
+  }
 
-    if (!player) {
+  async start(socket: Socket) {
+    if (!isHost(this, socket.id) || this.started) {
       return
     }
 
-    this.players = this.players.filter((p) => p.id !== playerId)
-    this.playerStatus.delete(playerId)
-
-    this.io.in(playerId).socketsLeave(this.gameId)
-    this.io
-      .to(player.id)
-      .emit("game:reset", "You have been kicked by the manager")
-    this.io.to(this.manager.id).emit("manager:playerKicked", player.id)
-
-    this.io.to(this.gameId).emit("game:totalPlayers", this.players.length)
-  }
-
-  reconnect(socket: Socket) {
-    const { clientId } = socket.handshake.auth
-    const isManager = this.manager.clientId === clientId
-
-    if (isManager) {
-      this.reconnectManager(socket)
-    } else {
-      this.reconnectPlayer(socket)
+    if (takeOverPacing(this, socket)) {
+      await this.begin()
     }
   }
 
-  private reconnectManager(socket: Socket) {
-    if (this.manager.connected) {
-      socket.emit("game:reset", "Manager already connected")
-
+  // Also run by the lobby timer of the pacing, without a host command
+  async begin() {
+    // Set before the first await, so a second start is dropped
+    if (this.started) {
       return
     }
 
-    socket.join(this.gameId)
-    this.manager.id = socket.id
-    this.manager.connected = true
+    this.started = true
+    this.starting = true
+    this.startedAt = this.clock()
+    this.sendOutline(this.managerRoom)
+    scheduleGameEnd(this)
 
-    const status = this.managerStatus ||
-      this.lastBroadcastStatus || {
-        name: STATUS.WAIT,
-        data: { text: "Waiting for players" },
-      }
+    const { timings } = this.config
 
-    socket.emit("manager:successReconnect", {
-      gameId: this.gameId,
-      currentQuestion: {
-        current: this.round.currentQuestion + 1,
-        total: this.quizz.questions.length,
-      },
-      status,
-      players: this.players,
+    this.broadcastStatus(STATUS.SHOW_START, {
+      time: timings.startCountdown,
+      subject: this.quizz.subject,
     })
-    socket.emit("game:totalPlayers", this.players.length)
-
-    registry.reactivateGame(this.gameId)
-    console.log(`Manager reconnected to game ${this.inviteCode}`)
-  }
 
-  private reconnectPlayer(socket: Socket) {
-    const { clientId } = socket.handshake.auth
-    const player = this.players.find((p) => p.clientId === clientId)
+    await sleep(timings.start)
 
-    if (!player) {
-      return
-    }
+    this.io.to(this.gameId).emit("game:startCooldown", { gameId: this.gameId })
+    await this.startCooldown(timings.startCountdown)
 
-    if (player.connected) {
-      socket.emit("game:reset", "Player already connected")
+    this.starting = false
+    this.enterRound()
+  }
 
-      return
-    }
+  sendOutline(target: string) {
+    this.io.to(target).emit(
+      "manager:quizzOutline",
+      this.quizz.questions
+        .filter(({ warmup }) => !warmup)
+        .map(({ question }) => question),
+    )
+  }
 
-    socket.join(this.gameId)
+  isCurrentRound(roundId: number) {
+    return this.started && this.roundId === roundId
+  }
 
-    const oldSocketId = player.id
-    player.id = socket.id
-    player.connected = true
+  isLastQuestion() {
+    return this.round.currentQuestion + 1 === this.quizz.questions.length
+  }
 
-    const status = this.playerStatus.get(oldSocketId) ||
-      this.lastBroadcastStatus || {
-        name: STATUS.WAIT,
-        data: { text: "Waiting for players" },
-      }
+  isSectionStart(index: number) {
+    const { section } = this.quizz.questions[index]
 
-    if (this.playerStatus.has(oldSocketId)) {
-      const oldStatus = this.playerStatus.get(oldSocketId)!
-      this.playerStatus.delete(oldSocketId)
-      this.playerStatus.set(socket.id, oldStatus)
+    if (!section) {
+      return false
     }
 
-    socket.emit("player:successReconnect", {
-      gameId: this.gameId,
-      currentQuestion: {
-        current: this.round.currentQuestion + 1,
-        total: this.quizz.questions.length,
-      },
-      status,
-      player: {
-        username: player.username,
-        points: player.points,
-      },
-    })
-    socket.emit("game:totalPlayers", this.players.length)
-    console.log(
-      `Player ${player.username} reconnected to game ${this.inviteCode}`,
-    )
+    return index === 0 || this.quizz.questions[index - 1].section !== section
   }
 
-  startCooldown(seconds: number): Promise<void> {
-    if (this.cooldown.active) {
-      return Promise.resolve()
+  enterRound() {
+    if (!this.started) {
+      return
     }
 
-    this.cooldown.active = true
-    let count = seconds - 1
-
-    return new Promise<void>((resolve) => {
-      const cooldownTimeout = setInterval(() => {
-        if (!this.cooldown.active || count <= 0) {
-          this.cooldown.active = false
-          clearInterval(cooldownTimeout)
-          resolve()
+    if (isGameExpired(this)) {
+      finishGame(this, true)
 
-          return
-        }
-
-        this.io.to(this.gameId).emit("game:cooldown", count)
-        count -= 1
-      }, 1000)
-    })
-  }
-
-  abortCooldown() {
-    this.cooldown.active &&= false
-  }
-
-  async start(socket: Socket) {
-    if (this.manager.id !== socket.id) {
       return
     }
 
-    if (this.started) {
+    const question = this.quizz.questions[this.round.currentQuestion]
+
+    if (!question.section || !this.isSectionStart(this.round.currentQuestion)) {
+      this.newRound()
+
       return
     }
 
-    this.started = true
+    this.intermission = true
+    this.status.clear()
 
-    this.broadcastStatus(STATUS.SHOW_START, {
-      time: 3,
-      subject: this.quizz.subject,
+    this.broadcastStatus(STATUS.SHOW_SECTION, {
+      title: question.section,
+      questionNumber: getQuestionProgress(this).current,
     })
+    schedulePacing(this, this.config.pacing.autoNextQuestion, () =>
+      this.leaveIntermission(),
+    )
+  }
 
-    await sleep(3)
-
-    this.io.to(this.gameId).emit("game:startCooldown")
-    await this.startCooldown(3)
-
+  leaveIntermission() {
+    this.intermission = false
     this.newRound()
   }
 
   async newRound() {
     const question = this.quizz.questions[this.round.currentQuestion]
+    const isLast = this.isLastQuestion()
 
     if (!this.started) {
       return
     }
 
-    this.playerStatus.clear()
+    // A redone question starts a new round, the previous run stops at its
+    // next step
+    this.roundId += 1
+    const { roundId } = this
+    rememberRound(this)
 
-    this.io.to(this.gameId).emit("game:updateQuestion", {
-      current: this.round.currentQuestion + 1,
-      total: this.quizz.questions.length,
-    })
+    this.clearUnlockTimeout()
+    this.status.clear()
+    this.round.playersAnswers = []
+    this.round.wagers.clear()
+    this.round.eliminated = null
+    this.round.extensions.clear()
+    this.round.removedAnswers.clear()
+    this.round.overtime = false
+    this.pausedBy.clear()
 
-    this.managerStatus = null
-    this.broadcastStatus(STATUS.SHOW_PREPARED, {
+    const progress = getQuestionProgress(this)
+
+    this.io
+      .to(this.gameId)
+      .emit("game:updateQuestion", { gameId: this.gameId, ...progress })
+
+    const preparedData = {
       totalAnswers: question.answers.length,
-      questionNumber: this.round.currentQuestion + 1,
-    })
+      questionNumber: progress.current,
+      section: question.section,
+      isLast,
+      warmup: question.warmup,
+    }
 
-    await sleep(2)
+    this.broadcastStatus(STATUS.SHOW_PREPARED, preparedData)
+    sendManagerPreview(this, STATUS.SHOW_PREPARED, preparedData)
 
-    if (!this.started) {
+    await sleep(this.config.timings.prepared)
+    await waitForResume(this)
+
+    if (!this.isCurrentRound(roundId)) {
       return
     }
 
-    this.broadcastStatus(STATUS.SHOW_QUESTION, {
+    const questionData = {
       question: question.question,
+      section: question.section,
+      isLast,
       image: question.image,
+      media: question.media,
+      wager: question.wager,
       cooldown: question.cooldown,
-    })
+    }
+
+    broadcastQuestion(this, STATUS.SHOW_QUESTION, questionData)
+    sendManagerPreview(this, STATUS.SHOW_QUESTION, questionData)
 
     await sleep(question.cooldown)
+    await waitForResume(this)
 
-    if (!this.started) {
+    if (!this.isCurrentRound(roundId)) {
       return
     }
 
-    this.round.startTime = Date.now()
+    this.round.startTime = this.clock()
 
-    this.broadcastStatus(STATUS.SELECT_ANSWER, {
+    broadcastQuestion(this, STATUS.SELECT_ANSWER, {
       question: question.question,
+      section: question.section,
+      isLast,
       answers: question.answers,
       image: question.image,
       video: question.video,
       audio: question.audio,
+      media: question.media,
+      wager: question.wager,
       time: question.time,
-      totalPlayer: this.players.length,
+      multiple: isMultiSelect(question) ? getSelectBounds(question) : undefined,
+      numeric: isNumeric(question),
+      allowAnswerChange: this.quizz.allowAnswerChange,
+      lockTime: this.config.minAnswerTime,
+      askConfidence: this.config.askConfidence,
+      totalPlayer: this.getAnsweringPlayers().length,
+      validKeys: getValidKeys(question),
+      questionIndex: this.round.currentQuestion,
     })
 
-    await this.startCooldown(question.time)
+    if (this.config.minAnswerTime > 0) {
+      this.unlockTimeout = setTimeout(() => {
+        this.unlockTimeout = null
+        this.io
+          .to(this.gameId)
+          .emit("game:answersUnlocked", { gameId: this.gameId })
+      }, this.config.minAnswerTime * 1000)
+    }
+
+    sendLifelines(this)
+
+    if (await this.startCooldown(question.time)) {
+      await runOvertime(this)
+    }
+
+    if (!this.isCurrentRound(roundId)) {
+      return
+    }
+
+    if (this.config.stagedReveal) {
+      startStagedReveal(this, question)
 
-    if (!this.started) {
       return
     }
 
     this.showResults(question)
   }
 
-  showResults(question: any) {
+  showResults(question: Question) {
+    sendFinalAnswerCount(this)
+
     const oldLeaderboard =
       this.leaderboard.length === 0
         ? this.players.map((p) => ({ ...p }))
         : this.leaderboard.map((p) => ({ ...p }))
 
-    const totalType = this.round.playersAnswers.reduce(
-      (acc: Record<number, number>, { answerId }) => {
-        acc[answerId] = (acc[answerId] || 0) + 1
-
-        return acc
-      },
-      {},
+    const sortedPlayers = scorePlayers(
+      this.players,
+      question,
+      this.round,
+      this.config,
     )
 
-    const sortedPlayers = this.players
-      .map((player) => {
-        const playerAnswer = this.round.playersAnswers.find(
-          (a) => a.playerId === player.id,
-        )
-
-        const isCorrect = playerAnswer
-          ? playerAnswer.answerId === question.solution
-          : false
-
-        const points =
-          playerAnswer && isCorrect ? Math.round(playerAnswer.points) : 0
-
-        player.points += points
-
-        return { ...player, lastCorrect: isCorrect, lastPoints: points }
-      })
-      .sort((a, b) => b.points - a.points)
-
     this.players = sortedPlayers
 
     sortedPlayers.forEach((player, index) => {
-      const rank = index + 1
-      const aheadPlayer = sortedPlayers[index - 1]
-
-      this.sendStatus(player.id, STATUS.SHOW_RESULT, {
-        correct: player.lastCorrect,
-        message: player.lastCorrect ? "Nice!" : "Too bad",
-        points: player.lastPoints,
-        myPoints: player.points,
-        rank,
-        aheadOfMe: aheadPlayer ? aheadPlayer.username : null,
-      })
+      this.sendStatus(
+        player.id,
+        STATUS.SHOW_RESULT,
+        buildPlayerResult(
+          player,
+          { rank: index + 1, aheadPlayer: sortedPlayers[index - 1] },
+          this.config,
+          this.random,
+        ),
+      )
     })
 
-    this.sendStatus(this.manager.id, STATUS.SHOW_RESPONSES, {
-      question: question.question,
-      responses: totalType,
-      correct: question.solution,
-      answers: question.answers,
-      image: question.image,
-    })
+    showResponses(this, question, sortedPlayers)
 
     this.leaderboard = sortedPlayers
     this.tempOldLeaderboard = oldLeaderboard
 
     this.round.playersAnswers = []
+    this.round.wagers.clear()
+
+    scheduleLeaderboard(this)
   }
-  selectAnswer(socket: Socket, answerId: number) {
-    const player = this.players.find((player) => player.id === socket.id)
-    const question = this.quizz.questions[this.round.currentQuestion]
 
-    if (!player) {
-      return
-    }
+  // Every connected, unmuted player has an answer, whoever answered then left
+  hasEveryoneAnswered() {
+    const answering = this.getAnsweringPlayers()
+
+    return (
+      answering.length > 0 &&
+      answering.every((p) =>
+        this.round.playersAnswers.some((a) => a.playerId === p.id),
+      )
+    )
+  }
 
-    if (this.round.playersAnswers.find((p) => p.playerId === socket.id)) {
+  endIfAllAnswered() {
+    if (!this.hasEveryoneAnswered()) {
       return
     }
 
-    this.round.playersAnswers.push({
-      playerId: player.id,
-      answerId,
-      points: timeToPoint(this.round.startTime, question.time),
-    })
-
-    this.sendStatus(socket.id, STATUS.WAIT, {
-      text: "Waiting for the players to answer",
-    })
+    this.cooldown.abortAfter(this.config.allAnsweredGrace, () =>
+      this.hasEveryoneAnswered(),
+    )
+  }
 
-    socket
-      .to(this.gameId)
-      .emit("game:playerAnswer", this.round.playersAnswers.length)
+  getConnectedPlayers() {
+    return this.players.filter((p) => p.connected)
+  }
 
-    this.io.to(this.gameId).emit("game:totalPlayers", this.players.length)
+  getAnsweringPlayers() {
+    return this.players.filter((p) => p.connected && !p.muted)
+  }
 
-    if (this.round.playersAnswers.length === this.players.length) {
-      this.abortCooldown()
-    }
+  countConnectedAnswers() {
+    return this.round.playersAnswers.filter(({ playerId }) =>
+      this.players.some((p) => p.id === playerId && p.connected),
+    ).length
   }
 
   nextRound(socket: Socket) {
-    if (!this.started) {
+    // The first round is entered by the start sequence itself
+    if (!this.started || this.starting) {
       return
     }
 
-    if (socket.id !== this.manager.id) {
+    if (!isHost(this, socket.id) || !takeOverPacing(this, socket)) {
       return
     }
 
+    if (this.intermission) {
+      this.leaveIntermission()
+
+      return
+    }
+
+    this.advanceRound()
+  }
+
+  advanceRound() {
     if (!this.quizz.questions[this.round.currentQuestion + 1]) {
       return
     }
 
     this.round.currentQuestion += 1
-    this.newRound()
+    this.enterRound()
   }
 
   abortRound(socket: Socket) {
@@ -503,39 +546,13 @@ class Game {
       return
     }
 
-    if (socket.id !== this.manager.id) {
+    if (!isHost(this, socket.id)) {
       return
     }
 
+    this.clearUnlockTimeout()
     this.abortCooldown()
   }
-
-  showLeaderboard() {
-    const isLastRound =
-      this.round.currentQuestion + 1 === this.quizz.questions.length
-
-    if (isLastRound) {
-      this.started = false
-
-      this.broadcastStatus(STATUS.FINISHED, {
-        subject: this.quizz.subject,
-        top: this.leaderboard.slice(0, 3),
-      })
-
-      return
-    }
-
-    const oldLeaderboard = this.tempOldLeaderboard
-      ? this.tempOldLeaderboard
-      : this.leaderboard
-
-    this.sendStatus(this.manager.id, STATUS.SHOW_LEADERBOARD, {
-      oldLeaderboard: oldLeaderboard.slice(0, 5),
-      leaderboard: this.leaderboard.slice(0, 5),
-    })
-
-    this.tempOldLeaderboard = null
-  }
 }
 
 export default Game
@@ -0,0 +1,389 @@
+import env from "@rahoot/socket/env"
+import Config from "@rahoot/socket/services/config"
+import Registry from "@rahoot/socket/services/registry"
+import { checkManagerPassword } from "@rahoot/socket/utils/attempts"
+import { buildResultsCsv, getPseudonym } from "@rahoot/socket/utils/export"
+import { getQuestionProgress } from "@rahoot/socket/utils/game"
+import {
+  importFromKahoot,
+  importFromQuizizz,
+} from "@rahoot/socket/utils/import"
+import { validateQuizz } from "@rahoot/socket/utils/quizz"
+import { getServerInfo } from "@rahoot/socket/utils/version"
+import { IncomingMessage, ServerResponse } from "http"
+
+type Context = {
+  req: IncomingMessage
+  res: ServerResponse
+  url: URL
+  params: Record<string, string>
+}
+
+type Route = {
+  method: string
+  path: RegExp
+  handler: (_ctx: Context) => void | Promise<void>
+}
+
+const MAX_BODY_SIZE = 1024 * 1024
+
+export const sendJson = (
+  res: ServerResponse,
+  status: number,
+  body: unknown,
+) => {
+  res.writeHead(status, { "Content-Type": "application/json" })
+  res.end(JSON.stringify(body))
+}
+
+const readBody = (req: IncomingMessage) =>
+  new Promise<string>((resolve, reject) => {
+    let body = ""
+
+    req.on("data", (chunk: Buffer) => {
+      body += chunk.toString()
+
+      if (body.length > MAX_BODY_SIZE) {
+        reject(new Error("Request body too large"))
+        req.destroy()
+      }
+    })
+
+    req.on("end", () => resolve(body))
+    req.on("error", reject)
+  })
+
+const parseJson = (body: string) => {
+  try {
+    return JSON.parse(body)
+  } catch {
+    throw new Error("Invalid JSON body")
+  }
+}
+
+const readJsonBody = async (req: IncomingMessage) =>
+  parseJson(await readBody(req))
+
+// Quizz ids map to file names, keep them free of path separators
+const isValidQuizzId = (id: string) => /^[\w-]+$/u.test(id)
+
+const importQuizz = async ({ req, res, url }: Context) => {
+  const id = url.searchParams.get("id") || ""
+  const format = url.searchParams.get("format")
+  const subject = url.searchParams.get("subject") || undefined
+
+  if (!isValidQuizzId(id)) {
+    sendJson(res, 400, { error: "Invalid or missing quizz id" })
+
+    return
+  }
+
+  if (Config.hasQuizz(id)) {
+    sendJson(res, 409, { error: `Quizz ${id} already exists` })
+
+    return
+  }
+
+  const body = await readBody(req)
+  const quizz =
+    format === "kahoot"
+      ? importFromKahoot(body, subject || id)
+      : importFromQuizizz(parseJson(body), subject)
+  const { errors, warnings } = validateQuizz(quizz)
+
+  if (errors.length > 0) {
+    sendJson(res, 422, { errors, warnings })
+
+    return
+  }
+
+  Config.saveQuizz(id, quizz)
+  sendJson(res, 201, { id, questions: quizz.questions.length, warnings })
+}
+
+const getQuizz = ({ res, params }: Context) => {
+  const quizz = isValidQuizzId(params.id) ? Config.readQuizz(params.id) : null
+
+  if (!quizz) {
+    sendJson(res, 404, { error: "Quizz not found" })
+
+    return
+  }
+
+  sendJson(res, 200, quizz)
+}
+
+const updateQuizz = async ({ req, res, params }: Context) => {
+  if (!isValidQuizzId(params.id) || !Config.hasQuizz(params.id)) {
+    sendJson(res, 404, { error: "Quizz not found" })
+
+    return
+  }
+
+  const body = await readJsonBody(req)
+  const { errors, warnings } = validateQuizz(body)
+
+  if (errors.length > 0) {
+    sendJson(res, 422, { errors, warnings })
+
+    return
+  }
+
+  Config.saveQuizz(params.id, body)
+  sendJson(res, 200, { id: params.id, warnings })
+}
+
+// Only read from a header, query parameters end up in access logs
+const isManager = (req: IncomingMessage) => {
+  const authorization = req.headers.authorization
+
+  if (!authorization?.startsWith("Bearer ")) {
+    return false
+  }
+
+  return checkManagerPassword(
+    req.socket.remoteAddress ?? "",
+    authorization.slice("Bearer ".length),
+  ).valid
+}
+
+const routes: Route[] = [
+  {
+    method: "GET",
+    path: /^\/version$/u,
+    handler: ({ res }) => {
+      sendJson(res, 200, getServerInfo())
+    },
+  },
+  {
+    method: "GET",
+    path: /^\/api\/games$/u,
+    handler: ({ req, res }) => {
+      if (!isManager(req)) {
+        sendJson(res, 401, { error: "Unauthorized" })
+
+        return
+      }
+
+      // Monitoring overview of the running games, without any player data,
+      // question numbers leave out warmups like on the game screens
+      const games = Registry.getInstance()
+        .getAllGames()
+        .map((game) => {
+          const progress = getQuestionProgress(game)
+
+          return {
+            gameId: game.gameId,
+            inviteCode: game.inviteCode,
+            players: game.players.length,
+            started: game.started,
+            currentQuestion: game.started ? progress.current : null,
+            totalQuestions: progress.total,
+          }
+        })
+
+      sendJson(res, 200, { games })
+    },
+  },
+  {
+    method: "GET",
+    path: /^\/api\/games\/(?<gameId>[^/]+)\/results\.csv$/u,
+    handler: ({ req, res, params }) => {
+      if (!isManager(req)) {
+        sendJson(res, 401, { error: "Unauthorized" })
+
+        return
+      }
+
+      const game = Registry.getInstance().getGameById(params.gameId)
+
+      if (!game) {
+        sendJson(res, 404, { error: "Game not found" })
+
+        return
+      }
+
+      res.writeHead(200, {
+        "Content-Type": "text/csv; charset=utf-8",
+        "Content-Disposition": `attachment; filename="results-${game.inviteCode}.csv"`,
+      })
+      res.end(
+        buildResultsCsv(
+          game.players,
+          game.stats,
+          game.config.leaderboardMode,
+          game.config.anonymizeExports
+            ? (player) => getPseudonym(game.gameId, player)
+            : undefined,
+        ),
+      )
+    },
+  },
+  {
+    method: "GET",
+    path: /^\/api\/games\/(?<gameId>[^/]+)\/events$/u,
+    handler: ({ req, res, params }) => {
+      if (!isManager(req)) {
+        sendJson(res, 401, { error: "Unauthorized" })
+
+        return
+      }
+
+      const game = Registry.getInstance().getGameById(params.gameId)
+
+      if (!game?.eventLog) {
+        sendJson(res, 404, {
+          error: game ? "Event recording is disabled" : "Game not found",
+        })
+
+        return
+      }
+
+      // The log keeps raw commands and statuses, usernames included
+      if (game.config.anonymizeExports) {
+        sendJson(res, 403, { error: "Exports are anonymized" })
+
+        return
+      }
+
+      sendJson(res, 200, {
+        gameId: game.gameId,
+        inviteCode: game.inviteCode,
+        seed: game.seed,
+        events: game.eventLog.events,
+      })
+    },
+  },
+  {
+    method: "GET",
+    path: /^\/api\/config$/u,
+    handler: ({ req, res }) => {
+      if (!isManager(req)) {
+        sendJson(res, 401, { error: "Unauthorized" })
+
+        return
+      }
+
+      sendJson(res, 200, { port: req.socket.localPort, ...Config.resolved() })
+    },
+  },
+  {
+    method: "POST",
+    path: /^\/api\/quizzes\/validate$/u,
+    handler: async ({ req, res }) => {
+      if (!isManager(req)) {
+        sendJson(res, 401, { error: "Unauthorized" })
+
+        return
+      }
+
+      const body = await readJsonBody(req).catch((error: Error) => {
+        sendJson(res, 400, { error: error.message })
+
+        return null
+      })
+
+      if (body === null) {
+        return
+      }
+
+      const { errors, warnings } = validateQuizz(body)
+
+      sendJson(res, 200, { valid: errors.length === 0, errors, warnings })
+    },
+  },
+  {
+    method: "POST",
+    path: /^\/api\/quizzes\/import$/u,
+    handler: async (ctx) => {
+      if (!isManager(ctx.req)) {
+        sendJson(ctx.res, 401, { error: "Unauthorized" })
+
+        return
+      }
+
+      const format = ctx.url.searchParams.get("format")
+
+      if (format !== "kahoot" && format !== "quizizz") {
+        sendJson(ctx.res, 400, { error: "Format must be kahoot or quizizz" })
+
+        return
+      }
+
+      try {
+        await importQuizz(ctx)
+      } catch (error) {
+        sendJson(ctx.res, 400, { error: (error as Error).message })
+      }
+    },
+  },
+  {
+    method: "GET",
+    path: /^\/api\/quizzes\/(?<id>[^/]+)$/u,
+    handler: (ctx) => {
+      if (!isManager(ctx.req)) {
+        sendJson(ctx.res, 401, { error: "Unauthorized" })
+
+        return
+      }
+
+      getQuizz(ctx)
+    },
+  },
+  {
+    method: "PUT",
+    path: /^\/api\/quizzes\/(?<id>[^/]+)$/u,
+    handler: async (ctx) => {
+      if (!isManager(ctx.req)) {
+        sendJson(ctx.res, 401, { error: "Unauthorized" })
+
+        return
+      }
+
+      try {
+        await updateQuizz(ctx)
+      } catch (error) {
+        sendJson(ctx.res, 400, { error: (error as Error).message })
+      }
+    },
+  },
+]
+
+const runRoute = async (route: Route, ctx: Context) => {
+  try {
+    await route.handler(ctx)
+  } catch (error) {
+    console.error(
+      `Failed to handle ${ctx.req.method} ${ctx.url.pathname}:`,
+      error,
+    )
+
+    if (!ctx.res.headersSent) {
+      sendJson(ctx.res, 500, { error: "Internal server error" })
+    }
+  }
+}
+
+export const handleRequest = (req: IncomingMessage, res: ServerResponse) => {
+  const url = new URL(req.url || "/", "http://localhost")
+
+  if (!url.pathname.startsWith(`${env.BASE_PATH}/`)) {
+    sendJson(res, 404, { error: "Not found" })
+
+    return
+  }
+
+  const path = url.pathname.slice(env.BASE_PATH.length)
+
+  for (const route of routes) {
+    const match = route.method === req.method && route.path.exec(path)
+
+    if (match) {
+      void runRoute(route, { req, res, url, params: match.groups || {} })
+
+      return
+    }
+  }
+
+  sendJson(res, 404, { error: "Not found" })
+}
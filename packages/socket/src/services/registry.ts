@@ -10,9 +10,11 @@ class Registry {
   private static instance: Registry | null = null
   private games: Game[] = []
   private emptyGames: EmptyGame[] = []
+  private endedInviteCodes = new Set<string>()
   private cleanupInterval: ReturnType<typeof setTimeout> | null = null
   private readonly EMPTY_GAME_TIMEOUT_MINUTES = 5
   private readonly CLEANUP_INTERVAL_MS = 60_000
+  private readonly MAX_ENDED_INVITE_CODES = 1000
 
   private constructor() {
     this.startCleanupTask()
@@ -37,6 +39,20 @@ class Registry {
     return this.games.find((g) => g.inviteCode === inviteCode)
   }
 
+  isInviteCodeEnded(inviteCode: string): boolean {
+    return this.endedInviteCodes.has(inviteCode)
+  }
+
+  private rememberEndedGame(game: Game): void {
+    this.endedInviteCodes.delete(game.inviteCode)
+    this.endedInviteCodes.add(game.inviteCode)
+
+    if (this.endedInviteCodes.size > this.MAX_ENDED_INVITE_CODES) {
+      const [oldest] = this.endedInviteCodes
+      this.endedInviteCodes.delete(oldest)
+    }
+  }
+
   getPlayerGame(gameId: string, clientId: string): Game | undefined {
     return this.games.find(
       (g) =>
@@ -46,16 +62,20 @@ class Registry {
 
   getManagerGame(gmageId: string, clientId: string): Game | undefined {
     return this.games.find(
-      (g) => g.gameId === gmageId && g.manager.clientId === clientId
+      (g) =>
+        g.gameId === gmageId &&
+        [g.manager, ...g.coHosts].some((h) => h.clientId === clientId)
     )
   }
 
-  getGameByManagerSocketId(socketId: string): Game | undefined {
-    return this.games.find((g) => g.manager.id === socketId)
+  getGamesByManagerSocketId(socketId: string): Game[] {
+    return this.games.filter((g) =>
+      [g.manager, ...g.coHosts].some((h) => h.id === socketId)
+    )
   }
 
-  getGameByPlayerSocketId(socketId: string): Game | undefined {
-    return this.games.find((g) => g.players.some((p) => p.id === socketId))
+  getGamesByPlayerSocketId(socketId: string): Game[] {
+    return this.games.filter((g) => g.players.some((p) => p.id === socketId))
   }
 
   markGameAsEmpty(game: Game): void {
@@ -87,6 +107,12 @@ class Registry {
 
   removeGame(gameId: string): boolean {
     const initialLength = this.games.length
+    const game = this.getGameById(gameId)
+
+    if (game) {
+      this.rememberEndedGame(game)
+    }
+
     this.games = this.games.filter((g) => g.gameId !== gameId)
     this.emptyGames = this.emptyGames.filter((g) => g.game.gameId !== gameId)
 
@@ -126,6 +152,8 @@ class Registry {
     const removed = this.emptyGames.filter((g) => !stillEmpty.includes(g))
     const removedGameIds = removed.map((r) => r.game.gameId)
 
+    removed.forEach((r) => this.rememberEndedGame(r.game))
+
     this.games = this.games.filter((g) => !removedGameIds.includes(g.gameId))
     this.emptyGames = stillEmpty
 
@@ -0,0 +1,90 @@
+import { Server } from "@rahoot/common/types/game/socket"
+import { Status, STATUS, StatusDataMap } from "@rahoot/common/types/game/status"
+import EventLog from "@rahoot/socket/services/eventLog"
+
+export type StatusData = { name: Status; data: StatusDataMap[Status] }
+
+const DEFAULT_STATUS: StatusData = {
+  name: STATUS.WAIT,
+  data: { text: "Waiting for players" },
+}
+
+class StatusStore {
+  io: Server
+  room: string
+  log: EventLog | null
+  last: StatusData | null
+  manager: StatusData | null
+  players: Map<string, StatusData>
+
+  constructor(io: Server, room: string, log: EventLog | null = null) {
+    this.io = io
+    this.room = room
+    this.log = log
+    this.last = null
+    this.manager = null
+    this.players = new Map()
+  }
+
+  broadcast<T extends Status>(name: T, data: StatusDataMap[T]) {
+    this.last = { name, data }
+    this.manager = null
+    this.emit(this.room, this.last)
+  }
+
+  sendManager<T extends Status>(
+    target: string,
+    name: T,
+    data: StatusDataMap[T],
+  ) {
+    this.manager = { name, data }
+    this.emit(target, this.manager)
+  }
+
+  sendPlayer<T extends Status>(
+    target: string,
+    name: T,
+    data: StatusDataMap[T],
+  ) {
+    const statusData = { name, data }
+
+    this.players.set(target, statusData)
+    this.emit(target, statusData)
+  }
+
+  private emit(target: string, statusData: StatusData) {
+    this.log?.record("status", statusData.name, {
+      target,
+      data: statusData.data,
+    })
+    this.io.to(target).emit("game:status", { gameId: this.room, ...statusData })
+  }
+
+  clear() {
+    this.players.clear()
+    this.manager = null
+  }
+
+  forgetPlayer(playerId: string) {
+    this.players.delete(playerId)
+  }
+
+  currentManager() {
+    return this.manager || this.last || DEFAULT_STATUS
+  }
+
+  movePlayer(oldId: string, newId: string) {
+    const status = this.players.get(oldId)
+
+    if (!status) {
+      return this.last || DEFAULT_STATUS
+    }
+
+    this.players.delete(oldId)
+    this.players.set(newId, status)
+
+    return status
+  }
+}
+
+export default StatusStore
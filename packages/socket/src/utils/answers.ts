@@ -0,0 +1,77 @@
+import {
+  Answer,
+  MAX_ANSWER_OPTIONS,
+  Question,
+} from "@rahoot/common/types/game"
+
+export const getSolutions = (question: Question) => [question.solution].flat()
+
+export const getValidKeys = (question: Question) =>
+  question.answers.map((_, index) => index)
+
+export const isNumeric = (question: Question) => question.type === "numeric"
+
+export const isMultiSelect = (question: Question) =>
+  Array.isArray(question.solution)
+
+export const getSelectBounds = (question: Question) =>
+  isMultiSelect(question)
+    ? {
+        min: question.minSelect ?? 1,
+        max: question.maxSelect ?? question.answers.length,
+      }
+    : { min: 1, max: 1 }
+
+export const isCorrectSelection = (question: Question, answerIds: number[]) => {
+  const solutions = getSolutions(question)
+
+  return (
+    answerIds.length === solutions.length &&
+    answerIds.every((id) => solutions.includes(id))
+  )
+}
+
+export const isCorrectAnswer = (question: Question, answer: Answer) => {
+  if (!isNumeric(question)) {
+    return isCorrectSelection(question, answer.answerIds)
+  }
+
+  return (
+    answer.value !== undefined &&
+    Math.abs(answer.value - question.target!) <= (question.tolerance ?? 0)
+  )
+}
+
+export const checkSelection = (
+  question: Question,
+  answerIds: unknown,
+  value?: unknown,
+) => {
+  if (isNumeric(question)) {
+    return typeof value === "number" && Number.isFinite(value)
+      ? null
+      : "Enter a number"
+  }
+
+  const isValid =
+    Array.isArray(answerIds) &&
+    answerIds.length <= MAX_ANSWER_OPTIONS &&
+    new Set(answerIds).size === answerIds.length &&
+    answerIds.every((id) => getValidKeys(question).includes(id))
+
+  if (!isValid) {
+    return "Invalid answer"
+  }
+
+  const { min, max } = getSelectBounds(question)
+
+  if (answerIds.length >= min && answerIds.length <= max) {
+    return null
+  }
+
+  if (min === max) {
+    return `Pick exactly ${min} answer${min > 1 ? "s" : ""}`
+  }
+
+  return `Pick between ${min} and ${max} answers`
+}
@@ -0,0 +1,80 @@
+import Config from "@rahoot/socket/services/config"
+
+const FAILED_ATTEMPTS_PER_WINDOW = 10
+const FAILED_ATTEMPT_WINDOW_MS = 60_000
+export const TOO_MANY_ATTEMPTS = "Too many attempts, please wait a minute"
+
+type Attempts = { windowStart: number; failures: number }
+
+// Over the limit, attempts are ignored until the window ends
+export const createAttemptLimiter = () => {
+  const attempts = new Map<string, Attempts>()
+
+  const isExpired = ({ windowStart }: Attempts, now: number) =>
+    now - windowStart > FAILED_ATTEMPT_WINDOW_MS
+
+  return {
+    isBlocked: (address: string) => {
+      const entry = attempts.get(address)
+
+      return (
+        entry !== undefined &&
+        entry.failures >= FAILED_ATTEMPTS_PER_WINDOW &&
+        !isExpired(entry, Date.now())
+      )
+    },
+
+    // Returns the message to send back, which becomes a warning on the
+    // attempt reaching the limit
+    fail: (address: string, message: string) => {
+      const now = Date.now()
+
+      attempts.forEach((entry, key) => {
+        if (isExpired(entry, now)) {
+          attempts.delete(key)
+        }
+      })
+
+      const entry = attempts.get(address) ?? { windowStart: now, failures: 0 }
+
+      entry.failures += 1
+      attempts.set(address, entry)
+
+      return entry.failures >= FAILED_ATTEMPTS_PER_WINDOW
+        ? TOO_MANY_ATTEMPTS
+        : message
+    },
+
+    reset: (address: string) => {
+      attempts.delete(address)
+    },
+  }
+}
+
+export type PasswordCheck = { valid: true } | { valid: false; error: string }
+
+const passwordAttempts = createAttemptLimiter()
+
+// Every manager password check, the manager login, co-hosts and the HTTP
+// API, shares one limiter so switching between them gives no extra tries
+export const checkManagerPassword = (
+  address: string,
+  password: unknown,
+): PasswordCheck => {
+  if (passwordAttempts.isBlocked(address)) {
+    return { valid: false, error: TOO_MANY_ATTEMPTS }
+  }
+
+  const { managerPassword } = Config.game()
+
+  if (!managerPassword || password !== managerPassword) {
+    return {
+      valid: false,
+      error: passwordAttempts.fail(address, "Invalid password"),
+    }
+  }
+
+  passwordAttempts.reset(address)
+
+  return { valid: true }
+}
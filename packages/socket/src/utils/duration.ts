@@ -0,0 +1,36 @@
+import Game from "@rahoot/socket/services/game"
+import { finishGame } from "@rahoot/socket/utils/leaderboard"
+
+const getMaxDuration = (game: Game) => game.config.maxGameDuration * 60 * 1000
+
+export const isGameExpired = (game: Game) => {
+  if (game.config.maxGameDuration <= 0 || game.startedAt === null) {
+    return false
+  }
+
+  return game.clock() - game.startedAt >= getMaxDuration(game)
+}
+
+export const expireGame = (game: Game) => {
+  console.log(`Game ${game.inviteCode} reached its maximum duration`)
+
+  finishGame(game, true)
+  game.abortCooldown()
+}
+
+export const scheduleGameEnd = (game: Game) => {
+  const { startedAt } = game
+
+  if (game.config.maxGameDuration <= 0) {
+    return
+  }
+
+  // Backstop for rounds that never reach a transition, e.g. a paused game
+  setTimeout(() => {
+    if (!game.started || game.startedAt !== startedAt) {
+      return
+    }
+
+    expireGame(game)
+  }, getMaxDuration(game))
+}
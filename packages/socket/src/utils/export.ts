@@ -0,0 +1,82 @@
+import {
+  LeaderboardMode,
+  Player,
+  QuestionStats,
+} from "@rahoot/common/types/game"
+import { comparePlayers } from "@rahoot/socket/utils/results"
+import { createHash } from "crypto"
+
+// Text starting like a formula is prefixed so spreadsheets don't run it,
+// numbers such as negative points are left as they are
+const isFormula = (value: string | number) =>
+  typeof value === "string" &&
+  /^[=+\-@]/u.test(value) &&
+  Number.isNaN(Number(value))
+
+const escapeCsv = (value: string | number) => {
+  const text = String(value)
+  const safe = isFormula(value) ? `'${text}` : text
+
+  return /[",\n\r]/u.test(safe) ? `"${safe.replace(/"/gu, '""')}"` : safe
+}
+
+const getCorrectness = (stats: QuestionStats, player: Player) => {
+  const result = stats.results[player.clientId]
+
+  if (!result) {
+    return ""
+  }
+
+  return result.correct ? 1 : 0
+}
+
+// Correct answers over the questions carrying the tag, e.g. "2/3"
+const getTagScore = (stats: QuestionStats[], tag: string, player: Player) => {
+  const tagged = stats.filter((question) => question.tags.includes(tag))
+  const correct = tagged.filter(
+    (question) => question.results[player.clientId]?.correct,
+  )
+
+  return `${correct.length}/${tagged.length}`
+}
+
+// Same player, same pseudonym within a game, but nothing links it to their
+// name or to their pseudonym in other games
+export const getPseudonym = (gameId: string, player: Player) => {
+  const hash = createHash("sha256")
+    .update(`${gameId}:${player.clientId}`)
+    .digest("hex")
+
+  return `Player ${hash.slice(0, 8)}`
+}
+
+export const buildResultsCsv = (
+  players: Player[],
+  allStats: QuestionStats[],
+  mode: LeaderboardMode,
+  getName = (player: Player) => player.username,
+) => {
+  const stats = allStats.filter((question) => !question.warmup)
+  const tags = [...new Set(stats.flatMap((question) => question.tags))]
+  const header = [
+    "rank",
+    "username",
+    "points",
+    ...stats.map((_, index) => `Q${index + 1}`),
+    ...tags.map((tag) => `tag:${tag}`),
+  ]
+
+  const rows = [...players]
+    .sort(comparePlayers(mode))
+    .map((player, index) => [
+      index + 1,
+      getName(player),
+      player.points,
+      ...stats.map((question) => getCorrectness(question, player)),
+      ...tags.map((tag) => getTagScore(stats, tag, player)),
+    ])
+
+  return [header, ...rows]
+    .map((row) => row.map(escapeCsv).join(","))
+    .join("\n")
+}
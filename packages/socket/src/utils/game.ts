@@ -1,6 +1,8 @@
+import { Quizz, Scoring } from "@rahoot/common/types/game"
 import { Socket } from "@rahoot/common/types/game/socket"
 import Game from "@rahoot/socket/services/game"
 import Registry from "@rahoot/socket/services/registry"
+import { Random } from "@rahoot/socket/utils/random"
 
 export const withGame = (
   gameId: string | undefined,
@@ -38,14 +40,126 @@ export const createInviteCode = (length = 6) => {
   return result
 }
 
-export const timeToPoint = (startTime: number, secondes: number): number => {
-  let points = 1000
+export const resolveInviteCode = (desired?: string) => {
+  const registry = Registry.getInstance()
+
+  if (desired && !registry.getGameByInviteCode(desired)) {
+    return desired
+  }
+
+  if (desired) {
+    console.log(`Invite code ${desired} already in use, generating a new one`)
+  }
+
+  let inviteCode = createInviteCode()
+
+  while (registry.getGameByInviteCode(inviteCode)) {
+    inviteCode = createInviteCode()
+  }
+
+  return inviteCode
+}
 
-  const actualTime = Date.now()
+const DEFAULT_MAX_POINTS = 1000
+
+const EXPONENTIAL_RATE = 3
+
+// Share of the points left after `progress` (0 to 1) of the decay period,
+// the exponential curve is rescaled to still reach zero at the end
+const decayPoints = (progress: number, decay: Scoring["decay"]) => {
+  if (decay === "exponential") {
+    const floor = Math.exp(-EXPONENTIAL_RATE)
+
+    return (Math.exp(-EXPONENTIAL_RATE * progress) - floor) / (1 - floor)
+  }
+
+  return 1 - progress
+}
+
+export const timeToPoint = (
+  startTime: number,
+  secondes: number,
+  actualTime: number,
+  {
+    maxPoints = DEFAULT_MAX_POINTS,
+    scoring,
+  }: { maxPoints?: number; scoring?: Scoring } = {},
+): number => {
   const tempsPasseEnSecondes = (actualTime - startTime) / 1000
+  // The window and the decay curve only apply to the fastBonus mode,
+  // linear always decreases steadily from the start
+  const fastBonus = scoring?.mode === "fastBonus" ? scoring : null
+  const fastWindow = fastBonus
+    ? Math.min(Math.max(0, fastBonus.fastWindow), secondes)
+    : 0
+
+  if (tempsPasseEnSecondes <= fastWindow) {
+    return maxPoints
+  }
+
+  const progress = Math.min(
+    1,
+    (tempsPasseEnSecondes - fastWindow) / (secondes - fastWindow),
+  )
 
-  points -= (1000 / secondes) * tempsPasseEnSecondes
-  points = Math.max(0, points)
+  return Math.max(
+    0,
+    maxPoints * decayPoints(progress, fastBonus?.decay ?? "linear"),
+  )
+}
+
+export const crossedThresholds = (
+  before: number,
+  after: number,
+  max: number,
+  thresholds: number[],
+) =>
+  thresholds.filter((threshold) => {
+    const limit = (max * threshold) / 100
+
+    return before < limit && after >= limit
+  })
+
+export const shuffle = <T>(items: T[], random: Random = Math.random): T[] => {
+  const result = [...items]
+
+  for (let i = result.length - 1; i > 0; i -= 1) {
+    const j = Math.floor(random() * (i + 1))
+    const item = result[i]
+
+    result[i] = result[j]
+    result[j] = item
+  }
+
+  return result
+}
 
-  return points
+const shuffleQuestions = (quizz: Quizz, random: Random) => {
+  if (!quizz.shuffleQuestions) {
+    return quizz.questions
+  }
+
+  const first = quizz.questions.filter((q) => q.pinned === "first")
+  const last = quizz.questions.filter((q) => q.pinned === "last")
+  const rest = quizz.questions.filter((q) => !q.pinned)
+
+  return [...first, ...shuffle(rest, random), ...last]
+}
+
+export const orderQuestions = (quizz: Quizz, random: Random) => {
+  const questions = shuffleQuestions(quizz, random)
+
+  return quizz.warmup
+    ? [{ ...quizz.warmup, warmup: true }, ...questions]
+    : questions
+}
+
+// The warmup question runs first and is left out of the numbering
+export const getQuestionProgress = (game: Game) => {
+  const warmups = game.quizz.questions.filter((q) => q.warmup).length
+
+  return {
+    current: game.round.currentQuestion + 1 - warmups,
+    total: game.quizz.questions.length - warmups,
+  }
 }
@@ -0,0 +1,42 @@
+import { Socket } from "@rahoot/common/types/game/socket"
+import Game from "@rahoot/socket/services/game"
+import { checkManagerPassword } from "@rahoot/socket/utils/attempts"
+import { reconnectManager } from "@rahoot/socket/utils/reconnect"
+
+export type Host = {
+  id: string
+  clientId: string
+  connected: boolean
+}
+
+export const getHosts = (game: Game): Host[] => [game.manager, ...game.coHosts]
+
+export const isHost = (game: Game, socketId: string) =>
+  getHosts(game).some((host) => host.id === socketId)
+
+export const findHost = (game: Game, clientId: string) =>
+  getHosts(game).find((host) => host.clientId === clientId)
+
+export const joinAsCoHost = (game: Game, socket: Socket, password: string) => {
+  const { clientId } = socket.handshake.auth
+  const check = checkManagerPassword(socket.handshake.address, password)
+
+  if (!check.valid) {
+    socket.emit("manager:errorMessage", check.error)
+
+    return
+  }
+
+  if (game.players.some((p) => p.clientId === clientId)) {
+    socket.emit("manager:errorMessage", "You are already playing this game")
+
+    return
+  }
+
+  if (!findHost(game, clientId)) {
+    game.coHosts.push({ id: socket.id, clientId, connected: false })
+    console.log(`Co-host joined game ${game.inviteCode}`)
+  }
+
+  reconnectManager(game, socket)
+}
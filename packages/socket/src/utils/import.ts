@@ -0,0 +1,154 @@
+import { Question, Quizz } from "@rahoot/common/types/game"
+
+type QuizizzQuestion = {
+  time?: number
+  structure?: {
+    kind?: string
+    answer?: number | number[]
+    query?: { text?: string; media?: { url?: string }[] }
+    options?: { text?: string }[]
+  }
+}
+
+const DEFAULT_COOLDOWN = 5
+const DEFAULT_TIME = 20
+
+const stripHtml = (text: string = "") =>
+  text
+    .replace(/<[^>]*>/gu, "")
+    .replace(/&nbsp;/gu, " ")
+    .trim()
+
+export const parseCsv = (text: string) => {
+  const rows: string[][] = []
+  let row: string[] = []
+  let cell = ""
+  let quoted = false
+
+  for (let i = 0; i < text.length; i += 1) {
+    const char = text[i]
+
+    if (quoted && char === '"' && text[i + 1] === '"') {
+      cell += '"'
+      i += 1
+    } else if (char === '"') {
+      quoted = !quoted
+    } else if (!quoted && char === ",") {
+      row.push(cell)
+      cell = ""
+    } else if (!quoted && (char === "\n" || char === "\r")) {
+      if (char === "\r" && text[i + 1] === "\n") {
+        i += 1
+      }
+
+      rows.push([...row, cell])
+      row = []
+      cell = ""
+    } else {
+      cell += char
+    }
+  }
+
+  if (cell || row.length > 0) {
+    rows.push([...row, cell])
+  }
+
+  return rows
+}
+
+const QUIZIZZ_ORIGIN = "https://quizizz.com"
+
+// Relative paths come from the origin platform, or stay local without one
+const resolveImageUrl = (url: string | undefined, origin?: string) => {
+  const trimmed = url?.trim()
+
+  if (!trimmed) {
+    return undefined
+  }
+
+  if (trimmed.startsWith("//")) {
+    return `https:${trimmed}`
+  }
+
+  if (!origin) {
+    return trimmed
+  }
+
+  try {
+    return new URL(trimmed, origin).href
+  } catch {
+    return undefined
+  }
+}
+
+const findColumn = (header: string[], name: string) =>
+  header.findIndex((cell) => cell.trim().toLowerCase().startsWith(name))
+
+export const importFromKahoot = (csv: string, subject: string): Quizz => {
+  const rows = parseCsv(csv)
+  const headerIndex = rows.findIndex((row) => findColumn(row, "question") >= 0)
+
+  if (headerIndex < 0) {
+    throw new Error("Missing Kahoot header row with a Question column")
+  }
+
+  const header = rows[headerIndex]
+  const questionColumn = findColumn(header, "question")
+  const answerColumns = [1, 2, 3, 4].map((n) =>
+    findColumn(header, `answer ${n}`),
+  )
+  const timeColumn = findColumn(header, "time limit")
+  const correctColumn = findColumn(header, "correct answer")
+  const imageColumn = findColumn(header, "image")
+
+  const questions = rows
+    .slice(headerIndex + 1)
+    .filter((row) => row[questionColumn]?.trim())
+    .map((row): Question => {
+      const answers = answerColumns
+        .filter((column) => column >= 0 && row[column]?.trim())
+        .map((column) => row[column].trim())
+      const correct = Number.parseInt(row[correctColumn] ?? "", 10)
+      const image = resolveImageUrl(row[imageColumn])
+
+      return {
+        question: row[questionColumn].trim(),
+        answers,
+        solution: Number.isNaN(correct) ? 0 : correct - 1,
+        cooldown: DEFAULT_COOLDOWN,
+        time: Number(row[timeColumn]) || DEFAULT_TIME,
+        ...(image ? { image } : {}),
+      }
+    })
+
+  return { subject, questions }
+}
+
+export const importFromQuizizz = (data: unknown, subject?: string): Quizz => {
+  const info = (
+    data as { quiz?: { info?: { name?: string; questions?: unknown } } }
+  )?.quiz?.info
+
+  if (!info || !Array.isArray(info.questions)) {
+    throw new Error("Missing quiz.info.questions in Quizizz export")
+  }
+
+  const questions = (info.questions as QuizizzQuestion[])
+    .filter(({ structure }) => structure?.kind === "MCQ")
+    .map(({ time, structure }): Question => {
+      const { query, options = [], answer } = structure!
+      const solution = Array.isArray(answer) ? answer[0] : answer
+      const image = resolveImageUrl(query?.media?.[0]?.url, QUIZIZZ_ORIGIN)
+
+      return {
+        question: stripHtml(query?.text),
+        answers: options.map((option) => stripHtml(option.text)),
+        solution: solution ?? 0,
+        cooldown: DEFAULT_COOLDOWN,
+        time: time ? Math.round(time / 1000) : DEFAULT_TIME,
+        ...(image ? { image } : {}),
+      }
+    })
+
+  return { subject: subject || info.name || "Imported quizz", questions }
+}
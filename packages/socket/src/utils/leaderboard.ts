@@ -0,0 +1,234 @@
+import { Socket } from "@rahoot/common/types/game/socket"
+import {
+  ManagerStatusDataMap,
+  STATUS,
+} from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import { isHost } from "@rahoot/socket/utils/host"
+import {
+  cancelPacing,
+  schedulePacing,
+  takeOverPacing,
+} from "@rahoot/socket/utils/pacing"
+import { formatPoints } from "@rahoot/socket/utils/points"
+import {
+  buildGameReview,
+  buildTagStats,
+  comparePlayers,
+  toLeaderboardEntries,
+} from "@rahoot/socket/utils/results"
+
+export const sendGameReview = (game: Game, target: string) => {
+  if (game.config.gameReview) {
+    game.io.to(target).emit("manager:gameReview", buildGameReview(game.stats))
+  }
+}
+
+export const finishGame = (game: Game, timeExpired = false) => {
+  const standings =
+    game.leaderboard.length > 0 ? game.leaderboard : game.players
+
+  cancelPacing(game)
+  game.started = false
+  game.finished = true
+
+  const finished = {
+    subject: game.quizz.subject,
+    top: toLeaderboardEntries(standings.slice(0, 3), game.config.pointsFormat),
+    ...(timeExpired ? { timeExpired } : {}),
+  }
+  const tags = buildTagStats(game.stats)
+
+  game.broadcastStatus(STATUS.FINISHED, finished)
+
+  // Performance by topic is for the host only
+  if (tags.length > 0) {
+    game.sendStatus(game.managerRoom, STATUS.FINISHED, { ...finished, tags })
+  }
+
+  sendGameReview(game, game.managerRoom)
+}
+
+const displayLeaderboard = (game: Game) => {
+  const { pointsFormat } = game.config
+
+  if (game.isLastQuestion()) {
+    finishGame(game)
+
+    return
+  }
+
+  if (game.config.hidePoints && !game.config.showManagerLeaderboard) {
+    game.tempOldLeaderboard = null
+    game.advanceRound()
+
+    return
+  }
+
+  const oldLeaderboard = game.tempOldLeaderboard
+    ? game.tempOldLeaderboard
+    : game.leaderboard
+
+  game.sendStatus(game.managerRoom, STATUS.SHOW_LEADERBOARD, {
+    oldLeaderboard: toLeaderboardEntries(
+      oldLeaderboard.slice(0, 5),
+      pointsFormat,
+    ),
+    leaderboard: toLeaderboardEntries(
+      game.leaderboard.slice(0, 5),
+      pointsFormat,
+    ),
+    mode: game.config.leaderboardMode,
+    muted: game.players.filter((p) => p.muted).map((p) => p.id),
+  })
+
+  if (!game.config.hidePoints) {
+    game.io.to(game.gameId).emit("player:updateLeaderboard", {
+      leaderboard: toLeaderboardEntries(game.leaderboard, pointsFormat),
+    })
+  }
+
+  game.tempOldLeaderboard = null
+  schedulePacing(game, game.config.pacing.autoNextQuestion, () =>
+    game.advanceRound(),
+  )
+}
+
+export const showLeaderboard = (game: Game, socket: Socket) => {
+  if (!isHost(game, socket.id)) {
+    return
+  }
+
+  if (game.stats.length <= game.round.currentQuestion) {
+    socket.emit(
+      "manager:errorMessage",
+      "No results to show yet, wait for the question to end",
+    )
+
+    return
+  }
+
+  if (takeOverPacing(game, socket)) {
+    displayLeaderboard(game)
+  }
+}
+
+export const scheduleLeaderboard = (game: Game) =>
+  schedulePacing(game, game.config.pacing.autoLeaderboard, () =>
+    displayLeaderboard(game),
+  )
+
+const broadcastStandings = (game: Game) => {
+  const { pointsFormat } = game.config
+  const current = game.status.currentManager()
+
+  if (current.name === STATUS.SHOW_LEADERBOARD) {
+    const data = current.data as ManagerStatusDataMap["SHOW_LEADERBOARD"]
+
+    game.sendStatus(game.managerRoom, STATUS.SHOW_LEADERBOARD, {
+      ...data,
+      oldLeaderboard: data.leaderboard,
+      leaderboard: toLeaderboardEntries(
+        game.leaderboard.slice(0, 5),
+        pointsFormat,
+      ),
+    })
+  }
+
+  if (!game.config.hidePoints && game.leaderboard.length > 0) {
+    game.io.to(game.gameId).emit("player:updateLeaderboard", {
+      leaderboard: toLeaderboardEntries(game.leaderboard, pointsFormat),
+    })
+  }
+}
+
+const STANDINGS_TOP = 5
+const STANDINGS_INTERVAL_MS = 2000
+
+const standingsRequests = new WeakMap<Game, Map<string, number>>()
+
+// Read-only, players can ask at any step, at most once per interval
+export const sendStandings = (game: Game, socket: Socket) => {
+  const player = game.players.find((p) => p.id === socket.id)
+
+  if (!player) {
+    return
+  }
+
+  if (game.config.hidePoints) {
+    socket.emit("game:errorMessage", "Points are hidden until the end")
+
+    return
+  }
+
+  const requests = standingsRequests.get(game) ?? new Map<string, number>()
+  const now = game.clock()
+  const last = requests.get(player.clientId)
+
+  if (last !== undefined && now - last < STANDINGS_INTERVAL_MS) {
+    return
+  }
+
+  requests.set(player.clientId, now)
+  standingsRequests.set(game, requests)
+
+  const { pointsFormat } = game.config
+  const standings =
+    game.leaderboard.length > 0 ? game.leaderboard : game.players
+  const index = standings.findIndex((p) => p.clientId === player.clientId)
+
+  socket.emit("player:standings", {
+    // Players who joined after the last leaderboard come last
+    rank: index === -1 ? standings.length + 1 : index + 1,
+    points: player.points,
+    formattedPoints: formatPoints(player.points, pointsFormat, player.locale),
+    top: toLeaderboardEntries(
+      standings.slice(0, STANDINGS_TOP),
+      pointsFormat,
+    ),
+  })
+}
+
+export const adjustScore = (
+  game: Game,
+  socket: Socket,
+  playerId: string,
+  delta: number,
+) => {
+  if (!isHost(game, socket.id) || !Number.isFinite(delta)) {
+    return
+  }
+
+  const player = game.players.find((p) => p.id === playerId)
+
+  if (!player) {
+    return
+  }
+
+  const { allowNegativePoints, leaderboardMode, pointsFormat } = game.config
+  const adjusted = player.points + Math.round(delta)
+  const points = allowNegativePoints ? adjusted : Math.max(0, adjusted)
+  const ranked = game.leaderboard.find((p) => p.id === playerId)
+
+  console.log(
+    `Adjusted ${player.username} points in game ${game.inviteCode}: ${player.points} -> ${points}`,
+  )
+
+  player.points = points
+
+  if (ranked) {
+    ranked.points = points
+  }
+
+  game.players.sort(comparePlayers(leaderboardMode))
+  game.leaderboard.sort(comparePlayers(leaderboardMode))
+
+  if (!game.config.hidePoints) {
+    game.io.to(player.id).emit("player:pointsAdjusted", {
+      points,
+      formattedPoints: formatPoints(points, pointsFormat, player.locale),
+    })
+  }
+
+  broadcastStandings(game)
+}
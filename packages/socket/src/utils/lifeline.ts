@@ -0,0 +1,114 @@
+import { Lifeline, LIFELINES, Player } from "@rahoot/common/types/game"
+import { Socket } from "@rahoot/common/types/game/socket"
+import { CommonStatusDataMap, STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import { getSolutions } from "@rahoot/socket/utils/answers"
+import { shuffle } from "@rahoot/socket/utils/game"
+
+export const hasLifelines = (game: Game) =>
+  LIFELINES.some((kind) => game.config.lifelines[kind] > 0)
+
+export const sendLifelines = (game: Game) => {
+  if (!hasLifelines(game)) {
+    return
+  }
+
+  game.players.forEach((player) => {
+    game.io.to(player.id).emit("player:lifelines", player.lifelines)
+  })
+}
+
+const applyFiftyFifty = (game: Game, player: Player) => {
+  const { removedAnswers } = game.round
+  const question = game.quizz.questions[game.round.currentQuestion]
+  const solutions = getSolutions(question)
+  const wrongAnswers = question.answers
+    .map((_, index) => index)
+    .filter((index) => !solutions.includes(index))
+  const count = Math.min(
+    Math.floor(question.answers.length / 2),
+    wrongAnswers.length - 1,
+  )
+
+  if (removedAnswers.has(player.clientId) || count <= 0) {
+    return false
+  }
+
+  const removed = shuffle(wrongAnswers, game.random).slice(0, count)
+
+  const data = game.status.last?.data as CommonStatusDataMap["SELECT_ANSWER"]
+
+  removedAnswers.set(player.clientId, removed)
+  game.sendStatus(player.id, STATUS.SELECT_ANSWER, {
+    ...data,
+    removedAnswers: removed,
+    validKeys: data.validKeys.filter((key) => !removed.includes(key)),
+  })
+
+  return true
+}
+
+const applyExtraTime = (game: Game, player: Player) => {
+  const { extensions } = game.round
+
+  if (extensions.has(player.clientId)) {
+    return false
+  }
+
+  extensions.set(player.clientId, game.config.extraTimeSeconds)
+
+  return true
+}
+
+export const applyLifeline = (game: Game, socket: Socket, kind: Lifeline) => {
+  const player = game.players.find((p) => p.id === socket.id)
+  const isAnswering =
+    game.status.last?.name === STATUS.SELECT_ANSWER && !game.round.overtime
+
+  if (!player || !isAnswering || !LIFELINES.includes(kind)) {
+    return
+  }
+
+  if (
+    player.lifelines[kind] <= 0 ||
+    game.round.playersAnswers.some((a) => a.playerId === player.id)
+  ) {
+    return
+  }
+
+  const used =
+    kind === "fiftyFifty"
+      ? applyFiftyFifty(game, player)
+      : applyExtraTime(game, player)
+
+  if (!used) {
+    return
+  }
+
+  player.lifelines[kind] -= 1
+  socket.emit("player:lifelines", player.lifelines)
+}
+
+export const runOvertime = async (game: Game) => {
+  const { extensions, playersAnswers } = game.round
+  const waiting = game.players.filter(
+    (player) =>
+      player.connected &&
+      extensions.has(player.clientId) &&
+      !playersAnswers.some((a) => a.playerId === player.id),
+  )
+
+  if (waiting.length === 0) {
+    return
+  }
+
+  const seconds = Math.max(
+    ...waiting.map((player) => extensions.get(player.clientId) || 0),
+  )
+
+  game.round.overtime = true
+  await game.cooldown.start(seconds, waiting.map((player) => player.id))
+}
+
+export const getExtension = (game: Game, player: Player) =>
+  game.round.extensions.get(player.clientId) || 0
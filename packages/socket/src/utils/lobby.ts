@@ -0,0 +1,278 @@
+import { LobbyOrder, Player } from "@rahoot/common/types/game"
+import { Socket } from "@rahoot/common/types/game/socket"
+import { usernameValidator } from "@rahoot/common/validators/auth"
+import Game from "@rahoot/socket/services/game"
+import { crossedThresholds, shuffle } from "@rahoot/socket/utils/game"
+import { findHost, isHost } from "@rahoot/socket/utils/host"
+import { schedulePacing } from "@rahoot/socket/utils/pacing"
+import { resolveLocale } from "@rahoot/socket/utils/points"
+import { Random } from "@rahoot/socket/utils/random"
+import { createReconnectToken } from "@rahoot/socket/utils/token"
+
+export const orderPlayers = (
+  players: Player[],
+  order: LobbyOrder,
+  random: Random,
+) => {
+  if (order === "alphabetical") {
+    return [...players].sort((a, b) => a.username.localeCompare(b.username))
+  }
+
+  if (order === "random") {
+    return shuffle(players, random)
+  }
+
+  return [...players]
+}
+
+const TOTAL_PLAYERS_DELAY_MS = 100
+const pendingCounters = new WeakMap<Game, Set<string>>()
+
+// One update per burst of joins, leaves or answers
+const scheduleCounter = (
+  game: Game,
+  counter: string,
+  delay: number,
+  send: () => void,
+) => {
+  const pending = pendingCounters.get(game) ?? new Set<string>()
+
+  if (pending.has(counter)) {
+    return
+  }
+
+  pending.add(counter)
+  pendingCounters.set(game, pending)
+  setTimeout(() => {
+    pending.delete(counter)
+    send()
+  }, delay)
+}
+
+export const getTotalPlayers = (game: Game) =>
+  game.started ? game.getAnsweringPlayers().length : game.players.length
+
+export const broadcastTotalPlayers = (game: Game) =>
+  scheduleCounter(game, "totalPlayers", TOTAL_PLAYERS_DELAY_MS, () => {
+    game.io.to(game.gameId).emit("game:totalPlayers", {
+      gameId: game.gameId,
+      count: getTotalPlayers(game),
+    })
+  })
+
+// Slow clients may miss a count, but not the one once everyone has answered
+export const broadcastAnswerCount = (game: Game) =>
+  scheduleCounter(game, "answers", game.config.answerCountInterval, () => {
+    const emitter = game.hasEveryoneAnswered() ? game.io : game.io.volatile
+
+    emitter.to(game.gameId).emit("game:playerAnswer", {
+      gameId: game.gameId,
+      count: game.countConnectedAnswers(),
+    })
+  })
+
+// Sent right away when the round ends, so screens that missed volatile
+// updates still show how many answered before the results
+export const sendFinalAnswerCount = (game: Game) => {
+  game.io.to(game.gameId).emit("game:playerAnswer", {
+    gameId: game.gameId,
+    count: game.countConnectedAnswers(),
+  })
+}
+
+export const broadcastLobby = (game: Game) => {
+  broadcastTotalPlayers(game)
+
+  if (game.config.showLobbyPlayers) {
+    game.io.to(game.gameId).emit("game:lobbyPlayers", {
+      gameId: game.gameId,
+      usernames: game.players.map((player) => player.username),
+    })
+  }
+}
+
+const warnCapacity = (game: Game, before: number) => {
+  const { maxPlayers, capacityWarnings } = game.config
+  const count = game.players.length
+
+  if (!maxPlayers) {
+    return
+  }
+
+  crossedThresholds(before, count, maxPlayers, capacityWarnings).forEach(
+    (threshold) => {
+      game.io
+        .to(game.managerRoom)
+        .emit("manager:capacityWarning", { count, maxPlayers, threshold })
+    },
+  )
+}
+
+export const joinGame = (
+  game: Game,
+  socket: Socket,
+  username: string,
+  locale?: unknown,
+) => {
+  if (findHost(game, socket.handshake.auth.clientId)) {
+    socket.emit("game:errorMessage", "You can't join a game you manage")
+
+    return
+  }
+
+  const isAlreadyConnected = game.players.find(
+    (p) => p.clientId === socket.handshake.auth.clientId,
+  )
+
+  if (isAlreadyConnected) {
+    socket.emit("game:errorMessage", "Player already connected")
+
+    return
+  }
+
+  const result = usernameValidator.safeParse(username)
+
+  if (result.error) {
+    socket.emit("game:errorMessage", result.error.issues[0].message)
+
+    return
+  }
+
+  const { maxPlayers } = game.config
+
+  if (maxPlayers && game.players.length >= maxPlayers) {
+    socket.emit("game:errorMessage", "Game is full")
+
+    return
+  }
+
+  socket.join(game.gameId)
+
+  const playerData = {
+    id: socket.id,
+    clientId: socket.handshake.auth.clientId,
+    connected: true,
+    muted: false,
+    username,
+    locale: resolveLocale(locale),
+    points: 0,
+    correctAnswers: 0,
+    streak: 0,
+    lifelines: { ...game.config.lifelines },
+  }
+
+  game.players.push(playerData)
+
+  game.io.to(game.managerRoom).emit("manager:newPlayer", playerData)
+  broadcastLobby(game)
+
+  // The lobby timer runs from the first player's arrival
+  if (!game.started && game.players.length === 1) {
+    schedulePacing(game, game.config.pacing.autoStart, () => {
+      if (game.players.length > 0) {
+        void game.begin()
+      }
+    })
+  }
+
+  warnCapacity(game, game.players.length - 1)
+
+  socket.emit("game:successJoin", {
+    gameId: game.gameId,
+    token: createReconnectToken(game.gameId, playerData.clientId),
+  })
+}
+
+export const mutePlayer = (
+  game: Game,
+  socket: Socket,
+  playerId: string,
+  muted: boolean,
+) => {
+  if (!isHost(game, socket.id)) {
+    return
+  }
+
+  const player = game.players.find((p) => p.id === playerId)
+
+  if (!player || player.muted === muted) {
+    return
+  }
+
+  player.muted = muted
+
+  game.io.to(player.id).emit("player:muted", muted)
+  game.io.to(game.managerRoom).emit("manager:playerMuted", { playerId, muted })
+
+  if (!game.started) {
+    return
+  }
+
+  // An answer already sent on the current question doesn't count either
+  if (muted) {
+    game.round.playersAnswers = game.round.playersAnswers.filter(
+      (answer) => answer.playerId !== playerId,
+    )
+    broadcastAnswerCount(game)
+  }
+
+  broadcastTotalPlayers(game)
+  game.endIfAllAnswered()
+}
+
+const MAX_PLAYER_MESSAGE_LENGTH = 200
+
+export const messagePlayer = (
+  game: Game,
+  socket: Socket,
+  playerId: string,
+  message: unknown,
+) => {
+  if (!isHost(game, socket.id) || typeof message !== "string") {
+    return
+  }
+
+  const player = game.players.find((p) => p.id === playerId)
+  const text = message
+    .replace(/\p{Cc}/gu, " ")
+    .trim()
+    .slice(0, MAX_PLAYER_MESSAGE_LENGTH)
+
+  if (!player) {
+    socket.emit("manager:errorMessage", "Player not found")
+
+    return
+  }
+
+  if (!text) {
+    return
+  }
+
+  game.io.to(player.id).emit("player:message", text)
+}
+
+export const kickPlayer = (game: Game, socket: Socket, playerId: string) => {
+  if (!isHost(game, socket.id)) {
+    return
+  }
+
+  const player = game.players.find((p) => p.id === playerId)
+
+  if (!player) {
+    return
+  }
+
+  game.players = game.players.filter((p) => p.id !== playerId)
+  game.status.forgetPlayer(playerId)
+
+  game.io.in(playerId).socketsLeave(game.gameId)
+  game.io
+    .to(player.id)
+    .emit("game:reset", {
+      gameId: game.gameId,
+      message: "You have been kicked by the manager",
+    })
+  game.io.to(game.managerRoom).emit("manager:playerKicked", player.id)
+
+  broadcastLobby(game)
+}
@@ -0,0 +1,35 @@
+import env from "@rahoot/socket/env"
+import { IncomingMessage, ServerResponse } from "http"
+
+const SOCKET_IO_PATH = `${env.BASE_PATH}${env.WS_PATH}/`
+
+const getPath = (req: IncomingMessage) =>
+  new URL(req.url || "/", "http://localhost").pathname
+
+export const logAccess = (req: IncomingMessage, res: ServerResponse) => {
+  if (env.ACCESS_LOG_LEVEL === "none") {
+    return
+  }
+
+  const path = getPath(req)
+
+  if (env.ACCESS_LOG_LEVEL === "info" && path.startsWith(SOCKET_IO_PATH)) {
+    return
+  }
+
+  const start = Date.now()
+
+  res.on("finish", () => {
+    console.log(
+      `${req.method} ${path} ${res.statusCode} ${Date.now() - start}ms`,
+    )
+  })
+}
+
+export const logUpgrade = (req: IncomingMessage) => {
+  if (env.ACCESS_LOG_LEVEL === "none") {
+    return
+  }
+
+  console.log(`${req.method} ${getPath(req)} 101 (websocket upgrade)`)
+}
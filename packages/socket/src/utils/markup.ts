@@ -0,0 +1,34 @@
+import { QuizzMarkup } from "@rahoot/common/types/game"
+
+const FORMATTING_TAGS = ["b", "i", "u", "em", "strong", "sub", "sup", "code"]
+
+const ENTITIES: Record<string, string> = {
+  "&": "&amp;",
+  "<": "&lt;",
+  ">": "&gt;",
+  '"': "&quot;",
+  "'": "&#39;",
+}
+
+const ESCAPED_TAG = new RegExp(
+  `&lt;(?<closing>/?)(?<name>${FORMATTING_TAGS.join("|")}|br)\\s*/?&gt;`,
+  "giu",
+)
+
+export const escapeHtml = (text: string) =>
+  text.replace(/[&<>"']/gu, (char) => ENTITIES[char])
+
+// Escape everything, then restore attribute-less formatting tags only
+export const sanitizeMarkup = (text: string, markup: QuizzMarkup) => {
+  const escaped = escapeHtml(text)
+
+  if (markup !== "formatting") {
+    return escaped
+  }
+
+  return escaped.replace(ESCAPED_TAG, (_, closing: string, name: string) => {
+    const tag = name.toLowerCase()
+
+    return tag === "br" ? "<br>" : `<${closing}${tag}>`
+  })
+}
@@ -0,0 +1,79 @@
+import {
+  MediaKind,
+  Question,
+  QuestionMedia,
+} from "@rahoot/common/types/game"
+import fs from "fs"
+import { join } from "path"
+
+const MEDIA_KINDS: MediaKind[] = ["image", "video", "audio"]
+
+const MIME_TYPES: Record<string, string> = {
+  png: "image/png",
+  jpg: "image/jpeg",
+  jpeg: "image/jpeg",
+  gif: "image/gif",
+  webp: "image/webp",
+  avif: "image/avif",
+  svg: "image/svg+xml",
+  mp4: "video/mp4",
+  webm: "video/webm",
+  ogv: "video/ogg",
+  mov: "video/quicktime",
+  mp3: "audio/mpeg",
+  ogg: "audio/ogg",
+  oga: "audio/ogg",
+  wav: "audio/wav",
+  m4a: "audio/mp4",
+  aac: "audio/aac",
+  flac: "audio/flac",
+}
+
+export const getMimeType = (url: string) => {
+  const path = url.split(/[?#]/u)[0]
+  const extension = /\.(?<ext>[a-z\d]+)$/iu.exec(path)?.groups?.ext
+
+  return extension ? MIME_TYPES[extension.toLowerCase()] : undefined
+}
+
+export const resolveMedia = (question: Question) => {
+  const media: QuestionMedia = {}
+
+  MEDIA_KINDS.forEach((kind) => {
+    const url = question[kind]
+
+    if (!url) {
+      return
+    }
+
+    const declared = question.media?.[kind]
+    const type = declared?.type || getMimeType(url)
+
+    media[kind] = { ...declared, ...(type ? { type } : {}) }
+  })
+
+  return Object.keys(media).length > 0 ? media : undefined
+}
+
+const isRemoteUrl = (url: string) =>
+  url.startsWith("//") || /^[a-z][a-z\d+.-]*:/iu.test(url)
+
+// A % not followed by an escape, e.g. in 100%.png, is checked as written
+const decodePath = (url: string) => {
+  const path = url.split(/[?#]/u)[0]
+
+  try {
+    return decodeURI(path)
+  } catch {
+    console.warn(`Media ${url} is not a valid URL, checking it as written`)
+
+    return path
+  }
+}
+
+// Local media paths of the question that don't exist under mediaPath,
+// remote URLs can't be checked at load time and are skipped
+export const findMissingMedia = (question: Question, mediaPath: string) =>
+  MEDIA_KINDS.flatMap((kind) => question[kind] ?? [])
+    .filter((url) => url && !isRemoteUrl(url))
+    .filter((url) => !fs.existsSync(join(mediaPath, decodePath(url))))
@@ -0,0 +1,56 @@
+import { Socket } from "@rahoot/common/types/game/socket"
+import Game from "@rahoot/socket/services/game"
+
+type PendingStep = {
+  timer: ReturnType<typeof setTimeout>
+  roundId: number
+}
+
+const pendingSteps = new WeakMap<Game, PendingStep>()
+
+export const cancelPacing = (game: Game) => {
+  const pending = pendingSteps.get(game)
+
+  if (pending) {
+    clearTimeout(pending.timer)
+    pendingSteps.delete(game)
+  }
+}
+
+// One pending step per game, dropped if a new round started in the meantime
+export const schedulePacing = (game: Game, delay: number, step: () => void) => {
+  cancelPacing(game)
+
+  if (delay <= 0) {
+    return
+  }
+
+  const { roundId } = game
+  const timer = setTimeout(() => {
+    pendingSteps.delete(game)
+
+    if (game.roundId === roundId) {
+      step()
+    }
+  }, delay * 1000)
+
+  pendingSteps.set(game, { timer, roundId })
+}
+
+// Called by the manager commands that move the game forward: they replace
+// the pending automatic step, unless the pacing doesn't let hosts interrupt
+export const takeOverPacing = (game: Game, socket: Socket) => {
+  if (!pendingSteps.has(game)) {
+    return true
+  }
+
+  if (!game.config.pacing.managerOverride) {
+    socket.emit("manager:errorMessage", "The game is advancing automatically")
+
+    return false
+  }
+
+  cancelPacing(game)
+
+  return true
+}
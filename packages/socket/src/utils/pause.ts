@@ -0,0 +1,167 @@
+import { Player } from "@rahoot/common/types/game"
+import { STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import Registry from "@rahoot/socket/services/registry"
+import {
+  broadcastAnswerCount,
+  broadcastTotalPlayers,
+} from "@rahoot/socket/utils/lobby"
+
+const heldRounds = new WeakMap<Game, (() => void)[]>()
+
+const isHeld = (game: Game) =>
+  game.cooldown.paused ||
+  (game.abandonedAt !== null && game.config.allDisconnected === "pause")
+
+// The prepared and question screens are plain sleeps without a cooldown to
+// pause, a round paused during one waits here before taking answers
+export const waitForResume = (game: Game) => {
+  if (!isHeld(game)) {
+    return Promise.resolve()
+  }
+
+  return new Promise<void>((resolve) => {
+    heldRounds.set(game, [...(heldRounds.get(game) ?? []), resolve])
+  })
+}
+
+const releaseRound = (game: Game) => {
+  heldRounds.get(game)?.forEach((resolve) => resolve())
+  heldRounds.delete(game)
+}
+
+export const resumeGame = (game: Game) => {
+  if (game.abandonedAt && game.getConnectedPlayers().length === 0) {
+    return
+  }
+
+  game.abandonedAt = null
+  releaseRound(game)
+
+  const pausedFor = game.cooldown.resume()
+
+  if (!pausedFor) {
+    return
+  }
+
+  game.round.startTime += pausedFor
+  game.io.to(game.gameId).emit("game:resumed", { gameId: game.gameId })
+}
+
+export const pauseFor = (game: Game, player: Player) => {
+  const isAnswering = game.status.last?.name === STATUS.SELECT_ANSWER
+
+  if (!game.config.pauseOnDisconnect || !isAnswering) {
+    return
+  }
+
+  game.pausedBy.add(player.clientId)
+
+  if (!game.cooldown.pause()) {
+    return
+  }
+
+  game.io.to(game.gameId).emit("game:paused", {
+    gameId: game.gameId,
+    username: player.username,
+  })
+
+  const { pausedAt } = game.cooldown
+
+  // Past the timeout the game stops waiting for everyone it paused for
+  setTimeout(() => {
+    if (game.cooldown.paused && game.cooldown.pausedAt === pausedAt) {
+      game.pausedBy.clear()
+      resumeGame(game)
+    }
+  }, game.config.pauseTimeout * 1000)
+}
+
+// A player back in an abandoned game ends the abandon, the game still waits
+// for the other players it paused for
+export const resumeFor = (game: Game, player: Player) => {
+  game.pausedBy.delete(player.clientId)
+
+  if (game.pausedBy.size === 0) {
+    resumeGame(game)
+  } else if (game.abandonedAt) {
+    game.abandonedAt = null
+    releaseRound(game)
+  }
+}
+
+const endAbandonedGame = (game: Game, abandonedAt: number) => {
+  if (game.abandonedAt !== abandonedAt) {
+    return
+  }
+
+  game.started = false
+  game.abortCooldown()
+  releaseRound(game)
+  game.io.to(game.gameId).emit("game:reset", {
+    gameId: game.gameId,
+    message: "All players left the game",
+  })
+  Registry.getInstance().removeGame(game.gameId)
+  console.log(`Ended game ${game.inviteCode} (all players disconnected)`)
+}
+
+export const handleAllDisconnected = (game: Game) => {
+  const { allDisconnected, allDisconnectedTimeout } = game.config
+
+  if (
+    !game.started ||
+    allDisconnected === "continue" ||
+    game.getConnectedPlayers().length > 0
+  ) {
+    return
+  }
+
+  const abandonedAt = game.clock()
+
+  game.abandonedAt = abandonedAt
+  game.io.to(game.managerRoom).emit("manager:allPlayersDisconnected", {
+    action: allDisconnected,
+    timeout: allDisconnectedTimeout,
+  })
+
+  if (allDisconnected === "pause") {
+    game.cooldown.pause()
+
+    return
+  }
+
+  setTimeout(
+    () => endAbandonedGame(game, abandonedAt),
+    allDisconnectedTimeout * 1000,
+  )
+}
+
+export const playerDisconnected = (game: Game, player: Player) => {
+  const { allowReconnect } = game.config
+
+  player.connected = false
+
+  if (allowReconnect) {
+    game.io.to(game.managerRoom).emit("manager:playerDisconnected", player)
+  } else {
+    game.players = game.players.filter((p) => p.id !== player.id)
+    game.status.forgetPlayer(player.id)
+    game.io.to(game.managerRoom).emit("manager:removePlayer", player.id)
+  }
+
+  broadcastTotalPlayers(game)
+
+  if (game.round.playersAnswers.some((a) => a.playerId === player.id)) {
+    broadcastAnswerCount(game)
+  }
+
+  // The players left may all have answered already
+  game.endIfAllAnswered()
+
+  if (allowReconnect) {
+    pauseFor(game, player)
+  }
+
+  handleAllDisconnected(game)
+}
@@ -0,0 +1,51 @@
+import { PointsFormat } from "@rahoot/common/types/game"
+
+export const DEFAULT_LOCALE = "en-US"
+
+const formats = new Map<string, Intl.NumberFormat>()
+
+const getFormat = (locale: string, compact: boolean) => {
+  const key = `${locale}:${compact}`
+  let format = formats.get(key)
+
+  if (!format) {
+    format = new Intl.NumberFormat(
+      locale,
+      compact ? { notation: "compact", maximumFractionDigits: 1 } : {},
+    )
+    formats.set(key, format)
+  }
+
+  return format
+}
+
+export const resolveLocale = (locale: unknown) => {
+  if (typeof locale !== "string" || locale.length > 35) {
+    return undefined
+  }
+
+  try {
+    return Intl.NumberFormat.supportedLocalesOf([locale])[0]
+  } catch {
+    return undefined
+  }
+}
+
+export const formatPoints = (
+  points: number,
+  format: PointsFormat,
+  locale: string = DEFAULT_LOCALE,
+) => {
+  const rounded = Math.round(points)
+
+  switch (format) {
+    case "grouped":
+      return getFormat(locale, false).format(rounded)
+
+    case "compact":
+      return getFormat(locale, true).format(rounded).toLowerCase()
+
+    default:
+      return String(rounded)
+  }
+}
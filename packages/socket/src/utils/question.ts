@@ -0,0 +1,37 @@
+import { STATUS, StatusDataMap } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+
+type QuestionStatus =
+  | typeof STATUS.SHOW_QUESTION
+  | typeof STATUS.SELECT_ANSWER
+  | typeof STATUS.SHOW_ELIMINATION
+
+const HIDDEN_QUESTION = "Look at the main screen"
+
+const hideTexts = <T extends QuestionStatus>(
+  data: StatusDataMap[T],
+): StatusDataMap[T] => {
+  const answers = "answers" in data ? (data.answers as string[]) : null
+
+  return {
+    ...data,
+    question: HIDDEN_QUESTION,
+    ...(answers ? { answers: answers.map(() => "") } : {}),
+  }
+}
+
+export const broadcastQuestion = <T extends QuestionStatus>(
+  game: Game,
+  status: T,
+  data: StatusDataMap[T],
+) => {
+  if (game.config.showQuestionOnDevices) {
+    game.broadcastStatus(status, data)
+
+    return
+  }
+
+  // Players only get the answer colors and shapes, hosts keep the texts
+  game.broadcastStatus(status, hideTexts(data))
+  game.sendStatus(game.managerRoom, status, data)
+}
@@ -0,0 +1,86 @@
+import { quizzValidator } from "@rahoot/common/validators/quizz"
+import z from "zod"
+
+export type QuizzReport = {
+  errors: string[]
+  warnings: string[]
+}
+
+const TRUE_FALSE_ANSWERS = ["True", "False"]
+
+// A "truefalse" question becomes a regular two-answer question
+const expandQuestion = (question: unknown) => {
+  const { type, answers, solution, ...rest } = (question ?? {}) as {
+    type?: unknown
+    answers?: unknown
+    solution?: unknown
+  }
+
+  // Players type a number, there are no answers to pick from
+  if (type === "numeric" && answers === undefined && solution === undefined) {
+    return { ...rest, type, answers: [], solution: [] }
+  }
+
+  if (
+    type !== "truefalse" ||
+    answers !== undefined ||
+    typeof solution !== "boolean"
+  ) {
+    return question
+  }
+
+  return { ...rest, answers: TRUE_FALSE_ANSWERS, solution: solution ? 0 : 1 }
+}
+
+export const expandQuizz = (data: unknown) => {
+  const quizz = data as { warmup?: unknown; questions?: unknown }
+
+  if (!quizz || typeof quizz !== "object") {
+    return data
+  }
+
+  return {
+    ...quizz,
+    ...(quizz.warmup ? { warmup: expandQuestion(quizz.warmup) } : {}),
+    ...(Array.isArray(quizz.questions)
+      ? { questions: quizz.questions.map(expandQuestion) }
+      : {}),
+  }
+}
+
+const formatIssue = ({ path, message }: z.core.$ZodIssue) => {
+  const where = path
+    .map((key) => (typeof key === "number" ? `#${key + 1}` : String(key)))
+    .join(" ")
+
+  return where ? `${where}: ${message}` : message
+}
+
+const getWarnings = (data: unknown) => {
+  const warnings: string[] = []
+  const questions = (data as { questions?: unknown })?.questions
+
+  if (!Array.isArray(questions)) {
+    return warnings
+  }
+
+  questions.forEach((question: { answers?: unknown }, index) => {
+    if (Array.isArray(question?.answers) && question.answers.length === 1) {
+      warnings.push(
+        `questions #${index + 1}: only one answer, players can't get it wrong`,
+      )
+    }
+  })
+
+  return warnings
+}
+
+export const validateQuizz = (data: unknown): QuizzReport => {
+  const expanded = expandQuizz(data)
+  const result = quizzValidator.safeParse(expanded)
+  const errors = result.success
+    ? []
+    : result.error.issues.map(formatIssue)
+
+  return { errors, warnings: getWarnings(expanded) }
+}
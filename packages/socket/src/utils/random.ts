@@ -0,0 +1,32 @@
+const MODULUS = 2147483647
+const MULTIPLIER = 16807
+
+export type Random = () => number
+
+export const randomSeed = () =>
+  Math.floor(Math.random() * (MODULUS - 1)) + 1
+
+export const normalizeSeed = (seed: unknown) => {
+  if (typeof seed !== "number" || !Number.isSafeInteger(seed)) {
+    return null
+  }
+
+  const value = Math.abs(seed)
+
+  // Seeds reported by a game are already valid and must replay the same game
+  if (value >= 1 && value < MODULUS) {
+    return value
+  }
+
+  return (value % (MODULUS - 1)) + 1
+}
+
+export const createRandom = (seed: number): Random => {
+  let state = seed
+
+  return () => {
+    state = (state * MULTIPLIER) % MODULUS
+
+    return (state - 1) / (MODULUS - 1)
+  }
+}
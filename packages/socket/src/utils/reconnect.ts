@@ -0,0 +1,138 @@
+import { Socket } from "@rahoot/common/types/game/socket"
+import Game from "@rahoot/socket/services/game"
+import Registry from "@rahoot/socket/services/registry"
+import { getQuestionProgress } from "@rahoot/socket/utils/game"
+import { findHost } from "@rahoot/socket/utils/host"
+import { sendGameReview } from "@rahoot/socket/utils/leaderboard"
+import {
+  broadcastTotalPlayers,
+  getTotalPlayers,
+  orderPlayers,
+} from "@rahoot/socket/utils/lobby"
+import { resumeFor } from "@rahoot/socket/utils/pause"
+import { createReconnectToken } from "@rahoot/socket/utils/token"
+
+const lastReconnects = new WeakMap<object, number>()
+
+// A flaky client may send several reconnects in a row: within the grace
+// window the next ones re-sync the same seat instead of being rejected
+const isDuplicateReconnect = (game: Game, seat: { connected: boolean }) => {
+  const at = lastReconnects.get(seat)
+
+  return (
+    seat.connected &&
+    at !== undefined &&
+    game.clock() - at < game.config.reconnectGrace * 1000
+  )
+}
+
+export const reconnectManager = (game: Game, socket: Socket) => {
+  const host = findHost(game, socket.handshake.auth.clientId)
+
+  if (!host) {
+    return
+  }
+
+  if (host.connected && !isDuplicateReconnect(game, host)) {
+    socket.emit("game:reset", {
+      gameId: game.gameId,
+      message: "Manager already connected",
+    })
+
+    return
+  }
+
+  socket.join([game.gameId, game.managerRoom])
+  host.id = socket.id
+  host.connected = true
+  lastReconnects.set(host, game.clock())
+
+  socket.emit("manager:successReconnect", {
+    gameId: game.gameId,
+    token: createReconnectToken(game.gameId, host.clientId),
+    seed: game.seed,
+    currentQuestion: getQuestionProgress(game),
+    status: game.status.currentManager(),
+    players: orderPlayers(game.players, game.config.lobbyOrder, game.random),
+  })
+  socket.emit("game:totalPlayers", {
+    gameId: game.gameId,
+    count: getTotalPlayers(game),
+  })
+
+  if (game.started) {
+    game.sendOutline(socket.id)
+  }
+
+  if (game.finished) {
+    sendGameReview(game, socket.id)
+  }
+
+  Registry.getInstance().reactivateGame(game.gameId)
+  console.log(`Manager reconnected to game ${game.inviteCode}`)
+}
+
+// The current round's answer follows the player to the new socket
+const moveAnswer = (game: Game, oldId: string, newId: string) => {
+  game.round.playersAnswers.forEach((answer) => {
+    if (answer.playerId === oldId) {
+      answer.playerId = newId
+    }
+  })
+}
+
+export const reconnectPlayer = (game: Game, socket: Socket) => {
+  const { clientId } = socket.handshake.auth
+  const player = game.players.find((p) => p.clientId === clientId)
+
+  if (!player) {
+    return
+  }
+
+  if (player.connected && !isDuplicateReconnect(game, player)) {
+    socket.emit("game:reset", {
+      gameId: game.gameId,
+      message: "Player already connected",
+    })
+
+    return
+  }
+
+  socket.join(game.gameId)
+
+  const oldSocketId = player.id
+  const isResync = oldSocketId === socket.id
+
+  player.id = socket.id
+  player.connected = true
+  lastReconnects.set(player, game.clock())
+  moveAnswer(game, oldSocketId, socket.id)
+
+  const status = game.status.movePlayer(oldSocketId, socket.id)
+
+  socket.emit("player:successReconnect", {
+    gameId: game.gameId,
+    token: createReconnectToken(game.gameId, clientId),
+    currentQuestion: getQuestionProgress(game),
+    status,
+    player: {
+      username: player.username,
+      points: game.config.hidePoints ? undefined : player.points,
+    },
+  })
+  socket.emit("player:lifelines", player.lifelines)
+
+  if (isResync) {
+    return
+  }
+
+  broadcastTotalPlayers(game)
+  game.io.to(game.managerRoom).emit("manager:playerReconnected", {
+    oldId: oldSocketId,
+    player,
+  })
+  resumeFor(game, player)
+  console.log(
+    `Player ${player.username} reconnected to game ${game.inviteCode}`,
+  )
+}
@@ -0,0 +1,80 @@
+import { Player } from "@rahoot/common/types/game"
+import { Socket } from "@rahoot/common/types/game/socket"
+import { STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import { isHost } from "@rahoot/socket/utils/host"
+
+type Score = Pick<Player, "points" | "correctAnswers" | "streak" | "lifelines">
+
+type RoundSnapshot = {
+  scores: Map<string, Score>
+  leaderboard: Player[]
+  stats: number
+}
+
+const snapshots = new WeakMap<Game, RoundSnapshot>()
+
+const EMPTY_SCORE = { points: 0, correctAnswers: 0, streak: 0 }
+
+// Taken when a question starts, so redoing it can undo anything the round
+// changed: points, streaks, used lifelines and the question's stats
+export const rememberRound = (game: Game) => {
+  snapshots.set(game, {
+    scores: new Map(
+      game.players.map((player) => [
+        player.clientId,
+        {
+          points: player.points,
+          correctAnswers: player.correctAnswers,
+          streak: player.streak,
+          lifelines: { ...player.lifelines },
+        },
+      ]),
+    ),
+    leaderboard: game.leaderboard.map((player) => ({ ...player })),
+    stats: game.stats.length,
+  })
+}
+
+const restoreRound = (game: Game, snapshot: RoundSnapshot) => {
+  game.players = game.players.map((player) => ({
+    ...player,
+    ...EMPTY_SCORE,
+    lifelines: { ...game.config.lifelines },
+    ...snapshot.scores.get(player.clientId),
+  }))
+  game.leaderboard = snapshot.leaderboard
+  game.tempOldLeaderboard = null
+  game.stats = game.stats.slice(0, snapshot.stats)
+  game.round.playersAnswers = []
+}
+
+export const redoQuestion = (game: Game, socket: Socket) => {
+  const snapshot = snapshots.get(game)
+
+  if (!isHost(game, socket.id) || !game.started || !snapshot) {
+    return
+  }
+
+  const { name } = game.status.currentManager()
+
+  // Once the leaderboard is out, the results are part of the game
+  if (
+    game.starting ||
+    game.intermission ||
+    name === STATUS.SHOW_LEADERBOARD ||
+    name === STATUS.FINISHED
+  ) {
+    socket.emit("manager:errorMessage", "This question can't be redone now")
+
+    return
+  }
+
+  console.log(
+    `Redoing question ${game.round.currentQuestion + 1} in game ${game.inviteCode}`,
+  )
+
+  game.abortCooldown()
+  restoreRound(game, snapshot)
+  game.newRound()
+}
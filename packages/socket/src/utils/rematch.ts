@@ -0,0 +1,82 @@
+import { Socket } from "@rahoot/common/types/game/socket"
+import { STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import { isHost } from "@rahoot/socket/utils/host"
+import { broadcastLobby, orderPlayers } from "@rahoot/socket/utils/lobby"
+import { cancelPacing } from "@rahoot/socket/utils/pacing"
+
+const resetRound = (game: Game) => {
+  cancelPacing(game)
+  game.clearUnlockTimeout()
+  game.started = false
+  game.finished = false
+  game.intermission = false
+  game.abandonedAt = null
+  // Steps still pending from the finished game belong to an older round
+  game.roundId += 1
+  game.stats = []
+  game.leaderboard = []
+  game.tempOldLeaderboard = null
+  game.pausedBy.clear()
+  game.status.clear()
+  game.round = {
+    currentQuestion: 0,
+    playersAnswers: [],
+    wagers: new Map(),
+    eliminated: null,
+    extensions: new Map(),
+    removedAnswers: new Map(),
+    overtime: false,
+    startTime: 0,
+  }
+}
+
+export const rematchGame = (
+  game: Game,
+  socket: Socket,
+  keepPlayers?: boolean,
+) => {
+  if (!isHost(game, socket.id) || !game.finished) {
+    return
+  }
+
+  const keepName = keepPlayers ?? game.config.rematchKeepPlayers
+
+  resetRound(game)
+
+  game.io
+    .to(game.gameId)
+    .emit("player:rematch", { gameId: game.gameId, keepName })
+
+  if (!keepName) {
+    game.players.forEach((player) => {
+      game.io.in(player.id).socketsLeave(game.gameId)
+    })
+  }
+
+  game.players = keepName
+    ? game.players
+        .filter((p) => p.connected)
+        .map((p) => ({
+          ...p,
+          points: 0,
+          correctAnswers: 0,
+          streak: 0,
+          lifelines: { ...game.config.lifelines },
+        }))
+    : []
+
+  if (keepName) {
+    game.broadcastStatus(STATUS.WAIT, { text: "Waiting for the players" })
+  }
+
+  game.sendStatus(game.managerRoom, STATUS.SHOW_ROOM, {
+    text: "Waiting for the players",
+    inviteCode: game.inviteCode,
+    lobbyOrder: game.config.lobbyOrder,
+    players: orderPlayers(game.players, game.config.lobbyOrder, game.random),
+  })
+  broadcastLobby(game)
+
+  console.log(`Rematch started for game ${game.inviteCode}`)
+}
@@ -0,0 +1,221 @@
+import {
+  Answer,
+  GameConfig,
+  LeaderboardEntry,
+  LeaderboardMode,
+  NumericSummary,
+  Player,
+  PointsFormat,
+  Question,
+  QuestionReview,
+  QuestionStats,
+  TagStats,
+} from "@rahoot/common/types/game"
+import { CommonStatusDataMap } from "@rahoot/common/types/game/status"
+import { isCorrectAnswer } from "@rahoot/socket/utils/answers"
+import { formatPoints } from "@rahoot/socket/utils/points"
+import { Random } from "@rahoot/socket/utils/random"
+import { getScorer } from "@rahoot/socket/utils/scoring"
+
+export type ScoredPlayer = Player & { lastCorrect: boolean; lastPoints: number }
+
+// Spread of the values typed on a numeric question, null without answers
+export const summarizeValues = (answers: Answer[]): NumericSummary | null => {
+  const values = answers
+    .flatMap(({ value }) => (value === undefined ? [] : [value]))
+    .sort((a, b) => a - b)
+  const middle = Math.floor(values.length / 2)
+
+  if (values.length === 0) {
+    return null
+  }
+
+  return {
+    count: values.length,
+    min: values[0],
+    max: values[values.length - 1],
+    median:
+      values.length % 2 === 0
+        ? (values[middle - 1] + values[middle]) / 2
+        : values[middle],
+  }
+}
+
+// One count per answer option, options nobody picked stay at zero
+export const countResponses = (question: Question, answers: Answer[]) =>
+  answers.reduce(
+    (acc, { answerIds }) => {
+      answerIds.forEach((answerId) => {
+        acc[answerId] += 1
+      })
+
+      return acc
+    },
+    question.answers.map(() => 0),
+  )
+
+export const comparePlayers =
+  (mode: LeaderboardMode) => (a: Player, b: Player) => {
+    if (mode === "accuracy" && a.correctAnswers !== b.correctAnswers) {
+      return b.correctAnswers - a.correctAnswers
+    }
+
+    return b.points - a.points
+  }
+
+export const scorePlayers = (
+  players: Player[],
+  question: Question,
+  round: { playersAnswers: Answer[]; wagers: Map<string, number> },
+  config: GameConfig,
+): ScoredPlayer[] => {
+  const scorer = getScorer(config.scorer)
+  const standings = [...players].sort(comparePlayers(config.leaderboardMode))
+
+  return players
+    .map((player) => {
+      const answer = round.playersAnswers.find((a) => a.playerId === player.id)
+      const isCorrect = answer ? isCorrectAnswer(question, answer) : false
+
+      if (question.warmup) {
+        return { ...player, lastCorrect: isCorrect, lastPoints: 0 }
+      }
+
+      player.streak = isCorrect ? player.streak + 1 : 0
+
+      const points = scorer.score({
+        question,
+        player,
+        answer,
+        isCorrect,
+        elapsed: answer ? answer.elapsed : null,
+        rank: standings.indexOf(player) + 1,
+        wager: round.wagers.get(player.clientId) || 0,
+        config,
+      })
+
+      player.points += points
+
+      if (isCorrect) {
+        player.correctAnswers += 1
+      }
+
+      return { ...player, lastCorrect: isCorrect, lastPoints: points }
+    })
+    .sort(comparePlayers(config.leaderboardMode))
+}
+
+const pickMessage = (messages: string[], fallback: string, random: Random) =>
+  messages.length > 0
+    ? messages[Math.floor(random() * messages.length)]
+    : fallback
+
+export const buildPlayerResult = (
+  player: ScoredPlayer,
+  standing: { rank: number; aheadPlayer?: ScoredPlayer },
+  config: GameConfig,
+  random: Random,
+): CommonStatusDataMap["SHOW_RESULT"] => {
+  const { rank, aheadPlayer } = standing
+  const { correct, wrong } = config.feedbackMessages
+  const result = {
+    correct: player.lastCorrect,
+    streak: player.streak,
+    message: player.lastCorrect
+      ? pickMessage(correct, "Nice!", random)
+      : pickMessage(wrong, "Too bad", random),
+  }
+
+  if (config.hidePoints) {
+    return result
+  }
+
+  const myPoints =
+    config.pointsDisplay === "round" ? player.lastPoints : player.points
+
+  return {
+    ...result,
+    points: player.lastPoints,
+    myPoints,
+    formattedPoints: formatPoints(
+      player.lastPoints,
+      config.pointsFormat,
+      player.locale,
+    ),
+    formattedMyPoints: formatPoints(
+      myPoints,
+      config.pointsFormat,
+      player.locale,
+    ),
+    rank,
+    aheadOfMe: aheadPlayer ? aheadPlayer.username : null,
+  }
+}
+
+export const toLeaderboardEntries = (
+  players: Player[],
+  format: PointsFormat,
+): LeaderboardEntry[] =>
+  players.map(({ id, username, points, correctAnswers }) => ({
+    id,
+    username,
+    points,
+    correctAnswers,
+    formattedPoints: formatPoints(points, format),
+  }))
+
+export const buildQuestionStats = (
+  question: Question,
+  responses: number[],
+  players: ScoredPlayer[],
+): QuestionStats => ({
+  question: question.question,
+  warmup: question.warmup,
+  tags: question.tags || [],
+  answers: question.answers,
+  solution: question.solution,
+  responses,
+  results: Object.fromEntries(
+    players.map((p) => [
+      p.clientId,
+      { correct: p.lastCorrect, points: p.lastPoints },
+    ]),
+  ),
+})
+
+// Answers given on each tag's questions and how many were correct
+export const buildTagStats = (stats: QuestionStats[]): TagStats[] => {
+  const byTag = new Map<string, TagStats>()
+
+  stats
+    .filter((question) => !question.warmup)
+    .forEach((question) => {
+      const results = Object.values(question.results)
+      const correct = results.filter((result) => result.correct).length
+
+      question.tags.forEach((tag) => {
+        const entry = byTag.get(tag) ?? { tag, correct: 0, total: 0 }
+
+        entry.correct += correct
+        entry.total += results.length
+        byTag.set(tag, entry)
+      })
+    })
+
+  return [...byTag.values()]
+}
+
+// Per-question breakdown for the host's post-game review, without the
+// per-player results
+export const buildGameReview = (stats: QuestionStats[]): QuestionReview[] =>
+  stats
+    .filter((question) => !question.warmup)
+    .map(({ results, ...question }) => {
+      const answers = Object.values(results)
+
+      return {
+        ...question,
+        answered: answers.length,
+        correctCount: answers.filter((result) => result.correct).length,
+      }
+    })
@@ -0,0 +1,93 @@
+import { Question } from "@rahoot/common/types/game"
+import { Socket } from "@rahoot/common/types/game/socket"
+import { STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import { getSolutions, isNumeric } from "@rahoot/socket/utils/answers"
+import { isHost } from "@rahoot/socket/utils/host"
+import { broadcastQuestion } from "@rahoot/socket/utils/question"
+import {
+  buildQuestionStats,
+  countResponses,
+  ScoredPlayer,
+  summarizeValues,
+} from "@rahoot/socket/utils/results"
+
+const broadcastEliminated = (game: Game, question: Question) => {
+  broadcastQuestion(game, STATUS.SHOW_ELIMINATION, {
+    question: question.question,
+    answers: question.answers,
+    image: question.image,
+    eliminated: [...(game.round.eliminated || [])],
+  })
+}
+
+export const startStagedReveal = (game: Game, question: Question) => {
+  game.round.eliminated = []
+  broadcastEliminated(game, question)
+}
+
+export const eliminateAnswer = (game: Game, socket: Socket) => {
+  const { eliminated } = game.round
+
+  if (!isHost(game, socket.id) || !eliminated) {
+    return
+  }
+
+  const question = game.quizz.questions[game.round.currentQuestion]
+  const solutions = getSolutions(question)
+  const remaining = question.answers
+    .map((_, index) => index)
+    .filter(
+      (index) => !solutions.includes(index) && !eliminated.includes(index),
+    )
+
+  if (remaining.length === 0) {
+    game.round.eliminated = null
+    game.showResults(question)
+
+    return
+  }
+
+  eliminated.push(remaining[Math.floor(game.random() * remaining.length)])
+  broadcastEliminated(game, question)
+}
+
+// Alphabetical, so the list says who answered but not in which order
+const listAnswerers = (game: Game) =>
+  game.players
+    .filter((player) =>
+      game.round.playersAnswers.some((answer) => answer.playerId === player.id),
+    )
+    .map((player) => player.username)
+    .sort((a, b) => a.localeCompare(b))
+
+export const showResponses = (
+  game: Game,
+  question: Question,
+  players: ScoredPlayer[],
+) => {
+  const { showAnswererNames, hideAnswerTiming } = game.config
+  const responses = countResponses(question, game.round.playersAnswers)
+
+  game.stats.push(buildQuestionStats(question, responses, players))
+
+  game.sendStatus(game.managerRoom, STATUS.SHOW_RESPONSES, {
+    question: question.question,
+    responses,
+    correct: getSolutions(question),
+    answers: question.answers,
+    image: question.image,
+    ...(showAnswererNames && hideAnswerTiming
+      ? { answerers: listAnswerers(game) }
+      : {}),
+    ...(isNumeric(question)
+      ? {
+          numeric: {
+            target: question.target!,
+            tolerance: question.tolerance ?? 0,
+            summary: summarizeValues(game.round.playersAnswers),
+          },
+        }
+      : {}),
+  })
+}
@@ -0,0 +1,107 @@
+import { Answer, GameConfig, Player, Question } from "@rahoot/common/types/game"
+import { clampWager } from "@rahoot/socket/utils/wager"
+import path from "path"
+import { pathToFileURL } from "url"
+
+export type ScoreContext = {
+  question: Question
+  player: Player
+  // Missing when the player didn't answer
+  answer?: Answer
+  isCorrect: boolean
+  // Milliseconds between the start of the question and the answer
+  elapsed: number | null
+  // Rank before this question, from 1
+  rank: number
+  wager: number
+  config: GameConfig
+}
+
+// Points earned on a question, negative to take points away. The player's
+// streak already counts this answer
+export type Scorer = {
+  score: (_context: ScoreContext) => number
+}
+
+const STREAK_BONUS = 100
+const MAX_STREAK_BONUS = 500
+
+const scoreConfidence = (context: ScoreContext) => {
+  const { player, answer, isCorrect, config } = context
+
+  if (!answer?.confidence) {
+    return 0
+  }
+
+  const { correct, wrong } = config.confidenceMultipliers[answer.confidence]
+
+  if (isCorrect) {
+    return Math.round(answer.points * correct)
+  }
+
+  return -Math.min(Math.round(answer.points * wrong), player.points)
+}
+
+const scoreAnswer = (context: ScoreContext) => {
+  const { question, player, answer, isCorrect } = context
+
+  if (question.wager) {
+    const wager = clampWager(context.wager, player.points)
+
+    return isCorrect ? wager : -wager
+  }
+
+  if (answer?.confidence) {
+    return scoreConfidence(context)
+  }
+
+  return answer && isCorrect ? Math.round(answer.points) : 0
+}
+
+// Consecutive correct answers, including this one, earn a growing bonus
+const scoreStreak = ({ player, isCorrect }: ScoreContext) =>
+  isCorrect ? Math.min(player.streak * STREAK_BONUS, MAX_STREAK_BONUS) : 0
+
+// Time based points, wagers and confidence, plus the streak bonus
+export const defaultScorer: Scorer = {
+  score: (context) => scoreAnswer(context) + scoreStreak(context),
+}
+
+const scorers = new Map<string, Scorer>([["default", defaultScorer]])
+
+// Custom scorers are registered under a name before the server starts and
+// picked with the `scorer` game option
+export const registerScorer = (name: string, scorer: Scorer) => {
+  scorers.set(name, scorer)
+}
+
+const isScorer = (value: unknown): value is Scorer =>
+  typeof value === "object" &&
+  value !== null &&
+  typeof (value as Scorer).score === "function"
+
+// Named exports only, so the default export can't replace the built-in one
+export const loadScorers = async (modulePath: string) => {
+  const exports: Record<string, unknown> = await import(
+    pathToFileURL(path.resolve(modulePath)).href
+  )
+  const names = Object.keys(exports).filter(
+    (name) => name !== "default" && isScorer(exports[name]),
+  )
+
+  names.forEach((name) => registerScorer(name, exports[name] as Scorer))
+
+  return names
+}
+
+export const getScorer = (name: string) => {
+  const scorer = scorers.get(name)
+
+  if (!scorer) {
+    console.warn(`Unknown scorer ${name}, using the default one`)
+
+    return defaultScorer
+  }
+
+  return scorer
+}
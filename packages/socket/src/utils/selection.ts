@@ -0,0 +1,162 @@
+import {
+  Answer,
+  Confidence,
+  CONFIDENCE_LEVELS,
+  Player,
+} from "@rahoot/common/types/game"
+import { Socket } from "@rahoot/common/types/game/socket"
+import { STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import { checkSelection, isNumeric } from "@rahoot/socket/utils/answers"
+import { timeToPoint } from "@rahoot/socket/utils/game"
+import { isHost } from "@rahoot/socket/utils/host"
+import { getExtension } from "@rahoot/socket/utils/lifeline"
+import {
+  broadcastAnswerCount,
+  broadcastTotalPlayers,
+} from "@rahoot/socket/utils/lobby"
+
+type Selection = {
+  answerIds: unknown[]
+  value?: unknown
+  confidence?: unknown
+  // Question the client is answering
+  questionIndex: unknown
+}
+
+const isConfidence = (value: unknown): value is Confidence =>
+  CONFIDENCE_LEVELS.some((level) => level === value)
+
+const STALE_MESSAGE = "You are no longer in this game"
+
+// Stale clients, e.g. kicked players still on the question screen, would
+// otherwise wait for a result that never comes
+const rejectStaleAnswer = (game: Game, socket: Socket) => {
+  if (isHost(game, socket.id)) {
+    return
+  }
+
+  if (game.config.staleAnswer === "reset") {
+    socket.emit("game:reset", { gameId: game.gameId, message: STALE_MESSAGE })
+  } else if (game.config.staleAnswer === "error") {
+    socket.emit("game:errorMessage", STALE_MESSAGE)
+  }
+}
+
+const canAnswer = (game: Game, player: Player) => {
+  const elapsed = game.clock() - game.round.startTime
+
+  // The status stays on the question while the results are shown, the
+  // cooldown only runs until the question ends
+  const isAnswering =
+    game.status.last?.name === STATUS.SELECT_ANSWER && game.cooldown.active
+
+  if (
+    !isAnswering ||
+    game.cooldown.paused ||
+    elapsed < game.config.minAnswerTime * 1000
+  ) {
+    return false
+  }
+
+  return !game.round.overtime || getExtension(game, player) > 0
+}
+
+const buildAnswer = (
+  game: Game,
+  player: Player,
+  selection: Selection,
+): Answer => {
+  const question = game.quizz.questions[game.round.currentQuestion]
+  const { answerIds, value, confidence } = selection
+
+  return {
+    playerId: player.id,
+    answerIds: answerIds as number[],
+    value: isNumeric(question) ? (value as number) : undefined,
+    elapsed: game.clock() - game.round.startTime,
+    points: timeToPoint(
+      game.round.startTime,
+      question.time + getExtension(game, player),
+      game.clock(),
+      { maxPoints: question.maxPoints, scoring: game.config.scoring },
+    ),
+    confidence:
+      game.config.askConfidence && isConfidence(confidence)
+        ? confidence
+        : undefined,
+  }
+}
+
+export const selectAnswer = (
+  game: Game,
+  socket: Socket,
+  selection: Selection,
+) => {
+  const player = game.players.find((p) => p.id === socket.id)
+  const question = game.quizz.questions[game.round.currentQuestion]
+  const { playersAnswers } = game.round
+
+  if (!player) {
+    rejectStaleAnswer(game, socket)
+
+    return
+  }
+
+  if (player.muted || !canAnswer(game, player)) {
+    return
+  }
+
+  // A lagging client may still be answering the previous question
+  if (selection.questionIndex !== game.round.currentQuestion) {
+    socket.emit("game:errorMessage", "This question is over")
+
+    return
+  }
+
+  const previous = playersAnswers.findIndex((a) => a.playerId === player.id)
+
+  if (previous !== -1 && !game.quizz.allowAnswerChange) {
+    return
+  }
+
+  const error = checkSelection(question, selection.answerIds, selection.value)
+
+  if (error) {
+    socket.emit("game:errorMessage", error)
+
+    return
+  }
+
+  const removed = game.round.removedAnswers.get(player.clientId) || []
+
+  if (selection.answerIds.some((id) => removed.includes(id as number))) {
+    return
+  }
+
+  const answer = buildAnswer(game, player, selection)
+
+  // A changed answer is scored at the current time
+  if (previous !== -1) {
+    playersAnswers[previous] = answer
+
+    return
+  }
+
+  playersAnswers.push(answer)
+
+  if (!game.quizz.allowAnswerChange) {
+    game.sendStatus(socket.id, STATUS.WAIT, {
+      text: "Waiting for the players to answer",
+    })
+  }
+
+  // Names popping up in answering order would show who is slow
+  if (game.config.showAnswererNames && !game.config.hideAnswerTiming) {
+    game.io.to(game.managerRoom).emit("manager:playerAnswered", player.username)
+  }
+
+  broadcastAnswerCount(game)
+  broadcastTotalPlayers(game)
+  game.endIfAllAnswered()
+}
@@ -0,0 +1,160 @@
+import {
+  ClientToServerEvents,
+  Socket,
+} from "@rahoot/common/types/game/socket"
+import env from "@rahoot/socket/env"
+import Registry from "@rahoot/socket/services/registry"
+
+export const watchIdleSocket = (socket: Socket) => {
+  const timeout = Number(env.IDLE_TIMEOUT) * 1000
+
+  if (!timeout) {
+    return
+  }
+
+  const registry = Registry.getInstance()
+  let timer: ReturnType<typeof setTimeout> | null = null
+
+  const arm = () => {
+    if (timer) {
+      clearTimeout(timer)
+    }
+
+    timer = setTimeout(() => {
+      const inGame =
+        registry.getGamesByManagerSocketId(socket.id).length > 0 ||
+        registry.getGamesByPlayerSocketId(socket.id).length > 0
+
+      // Checked again later, so a player who leaves or is kicked without
+      // sending anything else is still closed
+      if (inGame) {
+        arm()
+
+        return
+      }
+
+      console.log(`Closing idle socket: ${socket.id}`)
+      socket.emit("game:reset", {
+        message: "Connection closed due to inactivity",
+      })
+      socket.disconnect(true)
+    }, timeout)
+  }
+
+  arm()
+  socket.onAny(arm)
+  socket.on("disconnect", () => {
+    if (timer) {
+      clearTimeout(timer)
+    }
+  })
+}
+
+// Payload shape of the events whose handlers read fields from their message,
+// "data" ones also carry a nested data object
+const MESSAGE_SHAPES: Partial<
+  Record<keyof ClientToServerEvents, "message" | "data">
+> = {
+  "manager:joinAsCoHost": "message",
+  "manager:kickPlayer": "message",
+  "manager:mutePlayer": "message",
+  "manager:unmutePlayer": "message",
+  "manager:adjustScore": "message",
+  "manager:messagePlayer": "message",
+  "manager:startGame": "message",
+  "manager:abortQuiz": "message",
+  "manager:nextQuestion": "message",
+  "manager:showLeaderboard": "message",
+  "manager:eliminateAnswer": "message",
+  "manager:redoQuestion": "message",
+  "manager:rematch": "message",
+  "player:login": "data",
+  "player:selectedAnswer": "data",
+  "player:submitNumeric": "data",
+  "player:setWager": "data",
+  "player:useLifeline": "data",
+  "player:requestStandings": "message",
+}
+
+const KNOWN_EVENTS = new Set<string>([
+  ...Object.keys(MESSAGE_SHAPES),
+  "game:create",
+  "game:createRandom",
+  "game:ping",
+  "game:reconnect",
+  "manager:auth",
+  "player:join",
+])
+
+const PROTOCOL_ERRORS_PER_WINDOW = 5
+const PROTOCOL_ERROR_WINDOW_MS = 10_000
+
+const isObject = (value: unknown): value is Record<string, unknown> =>
+  typeof value === "object" && value !== null && !Array.isArray(value)
+
+const checkMessage = (event: string, message: unknown) => {
+  if (!KNOWN_EVENTS.has(event)) {
+    return "Unknown event"
+  }
+
+  const shape = MESSAGE_SHAPES[event as keyof ClientToServerEvents]
+
+  if (shape && !isObject(message)) {
+    return `Invalid message for ${event}: expected an object`
+  }
+
+  if (shape === "data" && isObject(message) && !isObject(message.data)) {
+    return `Invalid message for ${event}: expected a data object`
+  }
+
+  return null
+}
+
+export const guardMessages = (socket: Socket) => {
+  let windowStart = 0
+  let errors = 0
+
+  socket.use(([event, message], next) => {
+    const error = checkMessage(event, message)
+
+    if (!error) {
+      next()
+
+      return
+    }
+
+    const now = Date.now()
+
+    if (now - windowStart > PROTOCOL_ERROR_WINDOW_MS) {
+      windowStart = now
+      errors = 0
+    }
+
+    errors += 1
+
+    // Only known event names are echoed back, never the client payload
+    if (errors <= PROTOCOL_ERRORS_PER_WINDOW) {
+      socket.emit("game:protocolError", error)
+    }
+  })
+}
+
+export const recordCommands = (socket: Socket) => {
+  const registry = Registry.getInstance()
+
+  socket.use(([event, message], next) => {
+    const gameId = isObject(message) ? message.gameId : null
+    const game =
+      typeof gameId === "string" ? registry.getGameById(gameId) : undefined
+
+    if (game?.eventLog) {
+      const data = { ...message }
+
+      // Co-host requests carry the manager password
+      delete data.password
+      game.eventLog.record("command", event, { target: socket.id, data })
+    }
+
+    next()
+  })
+}
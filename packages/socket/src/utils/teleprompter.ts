@@ -0,0 +1,31 @@
+import { StatusDataMap, STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import { getSolutions } from "@rahoot/socket/utils/answers"
+
+type PreviewStatus = typeof STATUS.SHOW_PREPARED | typeof STATUS.SHOW_QUESTION
+
+export const sendManagerPreview = <T extends PreviewStatus>(
+  game: Game,
+  status: T,
+  data: StatusDataMap[T],
+) => {
+  const { hideAnswersUntilLock, managerTeleprompter } = game.config
+  const question = game.quizz.questions[game.round.currentQuestion]
+
+  if (managerTeleprompter) {
+    game.sendStatus(game.managerRoom, status, {
+      ...data,
+      answers: question.answers,
+      solution: getSolutions(question),
+    })
+
+    return
+  }
+
+  if (status === STATUS.SHOW_QUESTION && !hideAnswersUntilLock) {
+    game.sendStatus(game.managerRoom, status, {
+      ...data,
+      answers: question.answers,
+    })
+  }
+}
@@ -0,0 +1,53 @@
+import env from "@rahoot/socket/env"
+import { createHmac, randomBytes, timingSafeEqual } from "crypto"
+
+type ReconnectClaims = {
+  gameId: string
+  clientId: string
+  exp: number
+}
+
+// Games live in memory only, so a per-process secret is enough: tokens
+// become invalid on restart together with the games they point to
+const SECRET = randomBytes(32)
+
+const sign = (payload: string) =>
+  createHmac("sha256", SECRET).update(payload).digest("base64url")
+
+export const createReconnectToken = (gameId: string, clientId: string) => {
+  const claims: ReconnectClaims = {
+    gameId,
+    clientId,
+    exp: Date.now() + Number(env.RECONNECT_TOKEN_TTL) * 1000,
+  }
+  const payload = Buffer.from(JSON.stringify(claims)).toString("base64url")
+
+  return `${payload}.${sign(payload)}`
+}
+
+export const verifyReconnectToken = (token: unknown) => {
+  if (typeof token !== "string") {
+    return null
+  }
+
+  const [payload, signature = ""] = token.split(".")
+  const expected = Buffer.from(sign(payload))
+  const actual = Buffer.from(signature)
+
+  if (
+    actual.length !== expected.length ||
+    !timingSafeEqual(actual, expected)
+  ) {
+    return null
+  }
+
+  try {
+    const claims: ReconnectClaims = JSON.parse(
+      Buffer.from(payload, "base64url").toString(),
+    )
+
+    return claims.exp > Date.now() ? claims : null
+  } catch {
+    return null
+  }
+}
@@ -0,0 +1,8 @@
+import { MAX_ANSWER_OPTIONS, ServerInfo } from "@rahoot/common/types/game"
+import env from "@rahoot/socket/env"
+
+export const getServerInfo = (): ServerInfo => ({
+  version: env.APP_VERSION,
+  commit: env.GIT_COMMIT || null,
+  maxAnswerOptions: MAX_ANSWER_OPTIONS,
+})
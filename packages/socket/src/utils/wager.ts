@@ -0,0 +1,31 @@
+import { Socket } from "@rahoot/common/types/game/socket"
+import { STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+
+export const clampWager = (amount: number, points: number) =>
+  Math.max(0, Math.min(Math.floor(amount), points))
+
+export const setWager = (game: Game, socket: Socket, amount: number) => {
+  const player = game.players.find((p) => p.id === socket.id)
+  const question = game.quizz.questions[game.round.currentQuestion]
+  const isAnswering =
+    game.status.last?.name === STATUS.SELECT_ANSWER && game.cooldown.active
+
+  if (
+    !player ||
+    !question.wager ||
+    !isAnswering ||
+    typeof amount !== "number"
+  ) {
+    return
+  }
+
+  if (game.round.playersAnswers.some((a) => a.playerId === player.id)) {
+    return
+  }
+
+  const wager = clampWager(amount, player.points)
+
+  game.round.wagers.set(player.clientId, wager)
+  socket.emit("player:wagerSet", wager)
+}
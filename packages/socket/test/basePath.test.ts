@@ -0,0 +1,32 @@
+import { AddressInfo } from "net"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+
+// The environment is read once when the server module loads
+process.env.BASE_PATH = "/quiz/"
+process.env.ACCESS_LOG_LEVEL = "none"
+
+const { createRahootServer } = await import("@rahoot/socket/server")
+const { default: Registry } = await import("@rahoot/socket/services/registry")
+
+test("serves the routes and the websocket under the base path", async () => {
+  const { httpServer, io } = createRahootServer()
+
+  await new Promise<void>((resolve) => void httpServer.listen(0, resolve))
+
+  const { port } = httpServer.address() as AddressInfo
+  const origin = `http://localhost:${port}`
+
+  assert.equal((await fetch(`${origin}/quiz/version`)).status, 200)
+  assert.equal((await fetch(`${origin}/version`)).status, 404)
+
+  const polling = await fetch(
+    `${origin}/quiz/socket.io/?EIO=4&transport=polling`,
+  )
+
+  assert.equal(polling.status, 200)
+  assert.match(await polling.text(), /^0\{"sid"/u)
+
+  await io.close()
+  Registry.getInstance().cleanup()
+})
@@ -0,0 +1,44 @@
+import Config from "@rahoot/socket/services/config"
+import fs from "fs"
+import assert from "node:assert/strict"
+import { afterEach, mock, test } from "node:test"
+import path from "path"
+import { configPath, quizz, writeGameConfig, writeQuizz } from "./helpers"
+
+afterEach(() => writeGameConfig())
+
+test("merges confidence multipliers per level", () => {
+  writeGameConfig({ confidenceMultipliers: { low: { wrong: 0.1 } } })
+
+  assert.deepEqual(Config.game().confidenceMultipliers, {
+    low: { correct: 0.5, wrong: 0.1 },
+    medium: { correct: 1, wrong: 0.25 },
+    high: { correct: 1.5, wrong: 0.5 },
+  })
+})
+
+test("a broken quizz file is reported once without hiding the others", () => {
+  const broken = path.join(configPath, "quizz", "broken.json")
+  const error = mock.method(console, "error", () => undefined)
+  const warn = mock.method(console, "warn", () => undefined)
+
+  writeQuizz("valid", quizz)
+  writeQuizz("warned", {
+    ...quizz,
+    questions: [{ ...quizz.questions[0], answers: ["Yes"], solution: 0 }],
+  })
+  fs.writeFileSync(broken, "{")
+
+  const ids = Config.quizz().map(({ id }) => id)
+
+  Config.quizz()
+  fs.rmSync(broken)
+  error.mock.restore()
+  warn.mock.restore()
+
+  assert.ok(ids.includes("valid"))
+  assert.ok(ids.includes("warned"))
+  assert.ok(!ids.includes("broken"))
+  assert.equal(error.mock.callCount(), 1)
+  assert.equal(warn.mock.callCount(), 1)
+})
@@ -0,0 +1,9 @@
+import assert from "node:assert/strict"
+import { test } from "node:test"
+
+// The environment is read once when the env module loads
+process.env.WS_PATH = "socket.io"
+
+test("rejects a WS_PATH without a leading slash", async () => {
+  await assert.rejects(import("@rahoot/socket/env"))
+})
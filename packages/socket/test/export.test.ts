@@ -0,0 +1,43 @@
+import { Player } from "@rahoot/common/types/game"
+import { buildResultsCsv } from "@rahoot/socket/utils/export"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+
+const player = (
+  username: string,
+  points: number,
+  correctAnswers = 0,
+): Player => ({
+  id: username,
+  clientId: username,
+  connected: true,
+  muted: false,
+  username,
+  points,
+  correctAnswers,
+  streak: 0,
+  lifelines: { fiftyFifty: 0, extraTime: 0 },
+})
+
+test("neutralizes formulas but not negative points", () => {
+  const csv = buildResultsCsv(
+    [player("=HYPERLINK()", 10), player("-5", -200)],
+    [],
+    "points",
+  )
+
+  assert.equal(csv, "rank,username,points\n1,'=HYPERLINK(),10\n2,-5,-200")
+})
+
+test("ranks players like the leaderboard mode", () => {
+  const players = [player("alice", 900, 1), player("brian", 600, 2)]
+
+  assert.equal(
+    buildResultsCsv(players, [], "accuracy"),
+    "rank,username,points\n1,brian,600\n2,alice,900",
+  )
+  assert.equal(
+    buildResultsCsv(players, [], "points"),
+    "rank,username,points\n1,alice,900\n2,brian,600",
+  )
+})
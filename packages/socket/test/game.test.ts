@@ -0,0 +1,157 @@
+import Game from "@rahoot/socket/services/game"
+import { mutePlayer } from "@rahoot/socket/utils/lobby"
+import { playerDisconnected } from "@rahoot/socket/utils/pause"
+import sleep from "@rahoot/socket/utils/sleep"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import {
+  answer,
+  createServer,
+  createSocket,
+  createTestGame,
+  quizz,
+  received,
+  startQuestion,
+  stopGame,
+  waitFor,
+} from "./helpers"
+
+test("answers from disconnected players are not counted", async () => {
+  const { io, game, players } = createTestGame(["alice", "brian", "chloe"])
+  const [alice, brian] = players
+
+  await startQuestion(game)
+
+  answer(game, alice, 0)
+  answer(game, brian, 1)
+  playerDisconnected(game, game.players[0])
+
+  assert.equal(game.countConnectedAnswers(), 1)
+  assert.equal(game.getAnsweringPlayers().length, 2)
+
+  await waitFor(
+    () => received(io, game.gameId, "game:playerAnswer").length > 0,
+  )
+  assert.deepEqual(received(io, game.gameId, "game:playerAnswer").at(-1), [
+    { gameId: game.gameId, count: 1 },
+  ])
+
+  stopGame(game)
+})
+
+test("a player leaving after answering doesn't end the round", async () => {
+  const { game, players } = createTestGame(["alice", "brian"])
+  const [alice] = players
+
+  await startQuestion(game)
+
+  answer(game, alice, 0)
+  playerDisconnected(game, game.players[0])
+
+  assert.equal(game.hasEveryoneAnswered(), false)
+  assert.equal(game.cooldown.active, true)
+
+  stopGame(game)
+})
+
+test("a player leaving before answering ends the round", async () => {
+  const { game, players } = createTestGame(["alice", "brian"])
+  const [, brian] = players
+
+  await startQuestion(game)
+
+  answer(game, brian, 1)
+  assert.equal(game.cooldown.active, true)
+
+  playerDisconnected(game, game.players[0])
+
+  assert.equal(game.hasEveryoneAnswered(), true)
+  assert.equal(game.cooldown.active, false)
+
+  stopGame(game)
+})
+
+test("the last answer ends the round once a player has left", async () => {
+  const { game, players } = createTestGame(["alice", "brian"])
+  const [, brian] = players
+
+  await startQuestion(game)
+
+  playerDisconnected(game, game.players[0])
+  assert.equal(game.cooldown.active, true)
+
+  answer(game, brian, 1)
+
+  assert.equal(game.cooldown.active, false)
+
+  stopGame(game)
+})
+
+test("the answer count is sent reliably when the round ends", async () => {
+  const { io, game, players } = createTestGame(["alice", "brian"])
+  const [alice] = players
+
+  await startQuestion(game)
+
+  answer(game, alice, 0)
+  game.abortCooldown()
+  await waitFor(() => game.stats.length === 1)
+
+  const reliable = io.emitted.filter(
+    ({ event, volatile }) => event === "game:playerAnswer" && !volatile,
+  )
+
+  assert.deepEqual(
+    reliable.map(({ args }) => args),
+    [[{ gameId: game.gameId, count: 1 }]],
+  )
+
+  stopGame(game)
+})
+
+test("muting a player drops the answer they already sent", async () => {
+  const { game, manager, players } = createTestGame(["alice", "brian"])
+  const [alice] = players
+
+  await startQuestion(game)
+
+  answer(game, alice, 0)
+  mutePlayer(game, manager, "alice", true)
+
+  assert.equal(game.round.playersAnswers.length, 0)
+  assert.equal(game.cooldown.active, true)
+
+  stopGame(game)
+})
+
+test("an aborted round doesn't unlock the answers later", async () => {
+  const { io, game, manager } = createTestGame(["alice"])
+
+  game.config.minAnswerTime = 0.05
+  await startQuestion(game)
+
+  game.abortRound(manager)
+  await sleep(0.1)
+
+  assert.deepEqual(received(io, game.gameId, "game:answersUnlocked"), [])
+
+  stopGame(game)
+})
+
+test("a game created with the reported seed plays the same questions", () => {
+  const io = createServer()
+  const manager = createSocket(io, "manager")
+  const shuffled = {
+    ...quizz,
+    shuffleQuestions: true,
+    questions: ["First", "Second", "Third", "Fourth"].map((question) => ({
+      ...quizz.questions[0],
+      question: `${question} ?`,
+    })),
+  }
+  const game = new Game(io, manager, shuffled)
+  const replay = new Game(io, manager, shuffled, { seed: game.seed })
+
+  assert.equal(replay.seed, game.seed)
+  assert.deepEqual(replay.quizz.questions, game.quizz.questions)
+})
@@ -0,0 +1,186 @@
+import { Quizz } from "@rahoot/common/types/game"
+import { Server, Socket } from "@rahoot/common/types/game/socket"
+import { STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import Registry from "@rahoot/socket/services/registry"
+import { Clock } from "@rahoot/socket/utils/clock"
+import { joinGame } from "@rahoot/socket/utils/lobby"
+import { selectAnswer } from "@rahoot/socket/utils/selection"
+import sleep from "@rahoot/socket/utils/sleep"
+import fs from "fs"
+import { after } from "node:test"
+import os from "os"
+import path from "path"
+
+export type Emitted = {
+  target: string
+  event: string
+  args: unknown[]
+  volatile?: boolean
+}
+
+export type FakeServer = Server & {
+  emitted: Emitted[]
+  left: { target: string; room: string }[]
+}
+
+// Config is read from disk on each game, every test file gets its own folder
+export const configPath = fs.mkdtempSync(
+  path.join(os.tmpdir(), "rahoot-test-"),
+)
+
+process.env.CONFIG_PATH = configPath
+fs.mkdirSync(path.join(configPath, "quizz"))
+
+// No waiting between the steps of a game unless a test asks for it, the
+// config is written as is, like a hand edited game.json
+export const writeGameConfig = (config: Record<string, unknown> = {}) => {
+  fs.writeFileSync(
+    path.join(configPath, "game.json"),
+    JSON.stringify({
+      managerPassword: "PASSWORD",
+      timings: { start: 0, startCountdown: 1, prepared: 0 },
+      ...config,
+    }),
+  )
+}
+
+writeGameConfig()
+
+// The registry cleanup task would keep the test process alive
+after(() => Registry.getInstance().cleanup())
+
+export const writeQuizz = (id: string, quizz: Quizz) => {
+  fs.writeFileSync(
+    path.join(configPath, "quizz", `${id}.json`),
+    JSON.stringify(quizz),
+  )
+}
+
+export const quizz: Quizz = {
+  subject: "Test",
+  questions: [
+    {
+      question: "First ?",
+      answers: ["Yes", "No"],
+      solution: 0,
+      cooldown: 0,
+      time: 5,
+    },
+    {
+      question: "Second ?",
+      answers: ["Yes", "No"],
+      solution: 1,
+      cooldown: 0,
+      time: 5,
+    },
+  ],
+}
+
+const recorder = (
+  emitted: Emitted[],
+  target: string | string[],
+  volatile?: boolean,
+) => ({
+  emit: (event: string, ...args: unknown[]) => {
+    const message = { target: [target].flat().join(","), event, args }
+
+    emitted.push(volatile ? { ...message, volatile } : message)
+
+    return true
+  },
+})
+
+// Records the messages sent and the rooms left instead of acting on them,
+// room members are not tracked
+export const createServer = () => {
+  const emitted: Emitted[] = []
+  const left: FakeServer["left"] = []
+  const to = (target: string | string[]) => recorder(emitted, target)
+
+  return {
+    emitted,
+    left,
+    to,
+    in: (target: string) => ({
+      socketsLeave: (room: string) => left.push({ target, room }),
+    }),
+    volatile: {
+      to: (target: string | string[]) => recorder(emitted, target, true),
+    },
+  } as unknown as FakeServer
+}
+
+export const createSocket = (
+  io: FakeServer,
+  id: string,
+  clientId = `client-${id}`,
+) =>
+  ({
+    id,
+    handshake: { auth: { clientId }, address: "127.0.0.1" },
+    join: () => undefined,
+    emit: recorder(io.emitted, id).emit,
+    to: io.to,
+  }) as unknown as Socket
+
+export const received = (io: FakeServer, target: string, event: string) =>
+  io.emitted
+    .filter((message) => message.target === target && message.event === event)
+    .map(({ args }) => args)
+
+export const createTestGame = (
+  playerIds: string[],
+  options: { quizz?: Quizz; clock?: Clock } = {},
+) => {
+  const io = createServer()
+  const manager = createSocket(io, "manager")
+  const game = new Game(io, manager, options.quizz ?? quizz, {
+    seed: 1,
+    clock: options.clock,
+  })
+  const players = playerIds.map((id) => {
+    const socket = createSocket(io, id)
+
+    joinGame(game, socket, id)
+
+    return socket
+  })
+
+  return { io, game, manager, players }
+}
+
+export const waitFor = async (
+  condition: () => boolean,
+  timeout = 5,
+): Promise<void> => {
+  if (condition()) {
+    return
+  }
+
+  if (timeout <= 0) {
+    throw new Error("Timed out waiting for the game")
+  }
+
+  await sleep(0.01)
+
+  return waitFor(condition, timeout - 0.01)
+}
+
+// Runs the start sequence up to the first question accepting answers
+export const startQuestion = async (game: Game) => {
+  void game.begin()
+  await waitFor(() => game.status.last?.name === STATUS.SELECT_ANSWER)
+}
+
+export const answer = (game: Game, socket: Socket, answerId: number) =>
+  selectAnswer(game, socket, {
+    answerIds: [answerId],
+    questionIndex: game.round.currentQuestion,
+  })
+
+// Lets the pending cooldown resolve so the test process can exit
+export const stopGame = (game: Game) => {
+  game.started = false
+  game.abortCooldown()
+}
@@ -0,0 +1,96 @@
+import { handleRequest } from "@rahoot/socket/services/http"
+import Registry from "@rahoot/socket/services/registry"
+import { joinAsCoHost } from "@rahoot/socket/utils/host"
+import { IncomingMessage, ServerResponse } from "http"
+import assert from "node:assert/strict"
+import { after, test } from "node:test"
+// Helpers write a game config with PASSWORD as the manager password
+import {
+  createServer,
+  createSocket,
+  createTestGame,
+  quizz,
+  received,
+} from "./helpers"
+
+after(() => Registry.getInstance().cleanup())
+
+const request = (
+  url: string,
+  headers: Record<string, string> = {},
+  remoteAddress = "127.0.0.1",
+) => {
+  const req = {
+    method: "GET",
+    url,
+    headers,
+    socket: { remoteAddress },
+  } as unknown as IncomingMessage
+  const res = {
+    status: 0,
+    body: "",
+    writeHead(status: number) {
+      this.status = status
+    },
+    end(body = "") {
+      this.body = body
+    },
+    headersSent: false,
+  }
+
+  handleRequest(req, res as unknown as ServerResponse)
+
+  return res
+}
+
+test("takes the manager password from the Authorization header", () => {
+  const bearer = (password: string) => ({ authorization: `Bearer ${password}` })
+
+  assert.equal(request("/api/games", bearer("PASSWORD")).status, 200)
+  assert.equal(request("/api/games", bearer("nope")).status, 401)
+  assert.equal(request("/api/games?password=PASSWORD").status, 401)
+})
+
+test("wrong passwords are limited per address across sockets and HTTP", () => {
+  const { game } = createTestGame([])
+  const io = createServer()
+  const remoteAddress = "10.0.0.1"
+  const wrong = { authorization: "Bearer nope" }
+  const password = { authorization: "Bearer PASSWORD" }
+
+  for (let attempt = 0; attempt < 9; attempt += 1) {
+    assert.equal(request("/api/games", wrong, remoteAddress).status, 401)
+  }
+
+  // Each co-host attempt comes from a new connection of the same address
+  const socket = createSocket(io, "cohost")
+
+  socket.handshake.address = remoteAddress
+  joinAsCoHost(game, socket, "nope")
+
+  assert.deepEqual(received(io, "cohost", "manager:errorMessage"), [
+    ["Too many attempts, please wait a minute"],
+  ])
+  assert.equal(request("/api/games", password, remoteAddress).status, 401)
+  assert.equal(request("/api/games", password, "10.0.0.2").status, 200)
+})
+
+test("lists running games without counting the warmup question", () => {
+  const { game } = createTestGame(["alice"], {
+    quizz: {
+      ...quizz,
+      questions: [{ ...quizz.questions[0], warmup: true }, ...quizz.questions],
+    },
+  })
+
+  game.started = true
+  Registry.getInstance().addGame(game)
+
+  const { body } = request("/api/games", { authorization: "Bearer PASSWORD" })
+  const listed = JSON.parse(body).games.find(
+    ({ gameId }: { gameId: string }) => gameId === game.gameId,
+  )
+
+  assert.equal(listed.currentQuestion, 0)
+  assert.equal(listed.totalQuestions, 2)
+})
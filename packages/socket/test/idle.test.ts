@@ -0,0 +1,36 @@
+import { Socket } from "@rahoot/common/types/game/socket"
+import assert from "node:assert/strict"
+import { after, test } from "node:test"
+
+// The environment is read once when the env module loads
+process.env.IDLE_TIMEOUT = "0.05"
+
+const { default: Registry } = await import("@rahoot/socket/services/registry")
+const { watchIdleSocket } = await import("@rahoot/socket/utils/socket")
+const { default: sleep } = await import("@rahoot/socket/utils/sleep")
+const { createTestGame } = await import("./helpers")
+
+after(() => Registry.getInstance().cleanup())
+
+test("closes a socket once it is no longer in a game", async () => {
+  const { game, players } = createTestGame(["alice"])
+  let disconnected = false
+  const socket = {
+    ...players[0],
+    onAny: () => undefined,
+    on: () => undefined,
+    disconnect: () => {
+      disconnected = true
+    },
+  } as unknown as Socket
+
+  Registry.getInstance().addGame(game)
+  watchIdleSocket(socket)
+
+  await sleep(0.12)
+  assert.equal(disconnected, false)
+
+  game.players = []
+  await sleep(0.12)
+  assert.equal(disconnected, true)
+})
@@ -0,0 +1,56 @@
+import {
+  importFromKahoot,
+  importFromQuizizz,
+} from "@rahoot/socket/utils/import"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+
+const quizizzQuestion = (url: string) => ({
+  structure: {
+    kind: "MCQ",
+    answer: 0,
+    query: { text: "Which one ?", media: [{ url }] },
+    options: [{ text: "This" }, { text: "That" }],
+  },
+})
+
+test("resolves Quizizz media against the platform", () => {
+  const quizz = importFromQuizizz({
+    quiz: {
+      info: {
+        name: "Media",
+        questions: [
+          quizizzQuestion("/media/a.png"),
+          quizizzQuestion("//cdn.example.com/b.png"),
+          quizizzQuestion("https://example.com/c.png"),
+        ],
+      },
+    },
+  })
+
+  assert.deepEqual(
+    quizz.questions.map(({ image }) => image),
+    [
+      "https://quizizz.com/media/a.png",
+      "https://cdn.example.com/b.png",
+      "https://example.com/c.png",
+    ],
+  )
+})
+
+test("reads the Kahoot image column", () => {
+  const quizz = importFromKahoot(
+    [
+      "Question,Answer 1,Answer 2,Time limit,Correct answer(s),Image link",
+      "Which one ?,This,That,10,1,//cdn.example.com/a.png",
+      "And now ?,This,That,10,2,/images/b.png",
+      "Last ?,This,That,10,2,",
+    ].join("\n"),
+    "Kahoot",
+  )
+
+  assert.deepEqual(
+    quizz.questions.map(({ image }) => image),
+    ["https://cdn.example.com/a.png", "/images/b.png", undefined],
+  )
+})
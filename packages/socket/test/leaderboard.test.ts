@@ -0,0 +1,22 @@
+import { STATUS } from "@rahoot/common/types/game/status"
+import { showLeaderboard } from "@rahoot/socket/utils/leaderboard"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import { createTestGame, received } from "./helpers"
+
+test("the leaderboard waits for the first results", () => {
+  const { io, game, manager } = createTestGame(["alice"])
+
+  showLeaderboard(game, manager)
+
+  assert.deepEqual(received(io, "manager", "manager:errorMessage"), [
+    ["No results to show yet, wait for the question to end"],
+  ])
+  assert.ok(
+    received(io, game.managerRoom, "game:status").every(
+      ([status]) =>
+        (status as { name: string }).name !== STATUS.SHOW_LEADERBOARD,
+    ),
+  )
+  assert.equal(game.finished, false)
+})
@@ -0,0 +1,34 @@
+import { findMissingMedia } from "@rahoot/socket/utils/media"
+import fs from "fs"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import os from "os"
+import path from "path"
+import { quizz } from "./helpers"
+
+test("finds local media missing from the media folder", () => {
+  const mediaPath = fs.mkdtempSync(path.join(os.tmpdir(), "rahoot-media-"))
+
+  fs.mkdirSync(path.join(mediaPath, "images"))
+  fs.writeFileSync(path.join(mediaPath, "images", "100%.png"), "")
+  fs.writeFileSync(path.join(mediaPath, "images", "a b.png"), "")
+
+  const withMedia = (image: string) => ({ ...quizz.questions[0], image })
+
+  assert.deepEqual(
+    findMissingMedia(withMedia("/images/100%.png"), mediaPath),
+    [],
+  )
+  assert.deepEqual(
+    findMissingMedia(withMedia("/images/a%20b.png?v=1"), mediaPath),
+    [],
+  )
+  assert.deepEqual(
+    findMissingMedia(withMedia("/images/missing.png"), mediaPath),
+    ["/images/missing.png"],
+  )
+  assert.deepEqual(
+    findMissingMedia(withMedia("https://example.com/x.png"), mediaPath),
+    [],
+  )
+})
@@ -0,0 +1,97 @@
+import { STATUS } from "@rahoot/common/types/game/status"
+import Game from "@rahoot/socket/services/game"
+import { playerDisconnected } from "@rahoot/socket/utils/pause"
+import sleep from "@rahoot/socket/utils/sleep"
+import assert from "node:assert/strict"
+import { afterEach, test } from "node:test"
+import {
+  createSocket,
+  createTestGame,
+  FakeServer,
+  received,
+  startQuestion,
+  stopGame,
+  waitFor,
+  writeGameConfig,
+} from "./helpers"
+
+afterEach(() => writeGameConfig())
+
+const disconnectAll = (game: Game) =>
+  game.players.forEach((player) => playerDisconnected(game, player))
+
+const reconnect = (io: FakeServer, game: Game, index: number) => {
+  const player = game.players[index]
+
+  game.reconnect(createSocket(io, `${player.id}-back`, player.clientId))
+}
+
+test("every player leaving mid-question pauses the game", async () => {
+  writeGameConfig({ allDisconnected: "pause" })
+
+  const { io, game } = createTestGame(["alice", "brian"])
+
+  await startQuestion(game)
+  disconnectAll(game)
+
+  assert.equal(game.cooldown.paused, true)
+  assert.deepEqual(
+    received(io, game.managerRoom, "manager:allPlayersDisconnected"),
+    [[{ action: "pause", timeout: 60 }]],
+  )
+
+  reconnect(io, game, 0)
+
+  assert.equal(game.cooldown.paused, false)
+  assert.equal(game.abandonedAt, null)
+
+  stopGame(game)
+})
+
+test("a pause before the question holds the round", async () => {
+  writeGameConfig({
+    allDisconnected: "pause",
+    timings: { start: 0, startCountdown: 1, prepared: 0.5 },
+  })
+
+  const { io, game } = createTestGame(["alice", "brian"])
+
+  void game.begin()
+  await waitFor(() => game.status.last?.name === STATUS.SHOW_PREPARED)
+  disconnectAll(game)
+  await sleep(1)
+
+  assert.equal(game.status.last?.name, STATUS.SHOW_PREPARED)
+
+  reconnect(io, game, 1)
+  await waitFor(() => game.status.last?.name === STATUS.SELECT_ANSWER)
+
+  assert.equal(game.cooldown.paused, false)
+
+  stopGame(game)
+})
+
+test("a returning player only resumes their own pause", async () => {
+  writeGameConfig({
+    allDisconnected: "pause",
+    pauseOnDisconnect: true,
+    pauseTimeout: 1,
+  })
+
+  const { io, game } = createTestGame(["alice", "brian"])
+
+  await startQuestion(game)
+  disconnectAll(game)
+  reconnect(io, game, 0)
+
+  assert.equal(game.abandonedAt, null)
+  assert.equal(game.cooldown.paused, true)
+  assert.deepEqual([...game.pausedBy], ["client-brian"])
+
+  reconnect(io, game, 1)
+
+  assert.equal(game.cooldown.paused, false)
+  assert.equal(game.pausedBy.size, 0)
+
+  stopGame(game)
+})
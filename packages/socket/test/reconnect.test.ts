@@ -0,0 +1,72 @@
+import { playerDisconnected } from "@rahoot/socket/utils/pause"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import {
+  answer,
+  createSocket,
+  createTestGame,
+  received,
+  startQuestion,
+  waitFor,
+} from "./helpers"
+
+test("back to back reconnects re-sync the same seat", () => {
+  let now = 0
+  const { io, game } = createTestGame(["alice"], { clock: () => now })
+  const [player] = game.players
+
+  playerDisconnected(game, player)
+
+  const first = createSocket(io, "alice-1", player.clientId)
+  const second = createSocket(io, "alice-2", player.clientId)
+
+  game.reconnect(first)
+  now += 1000
+  game.reconnect(second)
+
+  assert.equal(received(io, "alice-1", "player:successReconnect").length, 1)
+  assert.equal(received(io, "alice-2", "player:successReconnect").length, 1)
+  assert.equal(received(io, "alice-2", "game:reset").length, 0)
+  assert.equal(player.id, "alice-2")
+  assert.equal(player.connected, true)
+})
+
+test("a reconnect after the grace window is rejected", () => {
+  let now = 0
+  const { io, game } = createTestGame(["alice"], { clock: () => now })
+  const [player] = game.players
+
+  playerDisconnected(game, player)
+  game.reconnect(createSocket(io, "alice-1", player.clientId))
+
+  now += (game.config.reconnectGrace + 1) * 1000
+  game.reconnect(createSocket(io, "alice-2", player.clientId))
+
+  assert.deepEqual(received(io, "alice-2", "game:reset"), [
+    [{ gameId: game.gameId, message: "Player already connected" }],
+  ])
+  assert.equal(player.id, "alice-1")
+})
+
+test("an answer given before reconnecting still counts", async () => {
+  let now = 0
+  const { io, game, players } = createTestGame(["alice", "brian"], {
+    clock: () => now,
+  })
+  const [alice, brian] = players
+  const [player] = game.players
+
+  await startQuestion(game)
+
+  now += 1000
+  answer(game, alice, 0)
+  playerDisconnected(game, player)
+  game.reconnect(createSocket(io, "alice-1", player.clientId))
+
+  assert.equal(game.countConnectedAnswers(), 1)
+
+  answer(game, brian, 1)
+  await waitFor(() => game.stats.length === 1)
+
+  assert.equal(player.points, 900)
+})
@@ -0,0 +1,31 @@
+import { rematchGame } from "@rahoot/socket/utils/rematch"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import { createTestGame } from "./helpers"
+
+test("a rematch with new players takes the old ones out of the room", () => {
+  const { io, game, manager } = createTestGame(["alice", "brian"])
+
+  game.finished = true
+  game.abandonedAt = 1
+
+  rematchGame(game, manager, false)
+
+  assert.deepEqual(io.left, [
+    { target: "alice", room: game.gameId },
+    { target: "brian", room: game.gameId },
+  ])
+  assert.deepEqual(game.players, [])
+  assert.equal(game.abandonedAt, null)
+})
+
+test("a rematch keeping the players leaves them in the room", () => {
+  const { io, game, manager } = createTestGame(["alice", "brian"])
+
+  game.finished = true
+
+  rematchGame(game, manager, true)
+
+  assert.deepEqual(io.left, [])
+  assert.equal(game.players.length, 2)
+})
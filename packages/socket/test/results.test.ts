@@ -0,0 +1,25 @@
+import { Answer } from "@rahoot/common/types/game"
+import { countResponses } from "@rahoot/socket/utils/results"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import { quizz } from "./helpers"
+
+const answerWith = (answerIds: number[]): Answer => ({
+  playerId: "player",
+  answerIds,
+  points: 0,
+  elapsed: 0,
+})
+
+test("counts options nobody picked as zero", () => {
+  const question = {
+    ...quizz.questions[0],
+    answers: ["A", "B", "C", "D"],
+  }
+
+  assert.deepEqual(
+    countResponses(question, [answerWith([1]), answerWith([1])]),
+    [0, 2, 0, 0],
+  )
+  assert.deepEqual(countResponses(question, []), [0, 0, 0, 0])
+})
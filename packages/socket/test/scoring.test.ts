@@ -0,0 +1,75 @@
+import { timeToPoint } from "@rahoot/socket/utils/game"
+import {
+  defaultScorer,
+  getScorer,
+  loadScorers,
+  ScoreContext,
+} from "@rahoot/socket/utils/scoring"
+import fs from "fs"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import os from "os"
+import path from "path"
+import { answer, createTestGame, startQuestion, waitFor } from "./helpers"
+
+test("scores answers from the game clock", async () => {
+  let now = 0
+  const { game, players } = createTestGame(["alice", "brian"], {
+    clock: () => now,
+  })
+  const [alice, brian] = players
+
+  await startQuestion(game)
+
+  // A fifth and four fifths of the 5 seconds to answer
+  now += 1000
+  answer(game, alice, 0)
+  now += 3000
+  answer(game, brian, 0)
+
+  await waitFor(() => game.stats.length === 1)
+
+  // Time based points plus the streak bonus of a first correct answer
+  assert.deepEqual(
+    game.leaderboard.map(({ username, points }) => ({ username, points })),
+    [
+      { username: "alice", points: 900 },
+      { username: "brian", points: 300 },
+    ],
+  )
+})
+
+test("registers the named scorers exported by a module", async () => {
+  const folder = fs.mkdtempSync(path.join(os.tmpdir(), "rahoot-scorer-"))
+  const modulePath = path.join(folder, "scorers.mjs")
+
+  fs.writeFileSync(
+    modulePath,
+    [
+      "export const flat = { score: ({ isCorrect }) => (isCorrect ? 1 : 0) }",
+      "export const notAScorer = 42",
+      "export default { score: () => 1000 }",
+    ].join("\n"),
+  )
+
+  assert.deepEqual(await loadScorers(modulePath), ["flat"])
+
+  const context = { isCorrect: true } as ScoreContext
+
+  assert.equal(getScorer("flat").score(context), 1)
+  assert.equal(getScorer("default"), defaultScorer)
+})
+
+test("only the fastBonus mode follows the decay curve", () => {
+  const scoring = { fastWindow: 2, decay: "exponential" } as const
+
+  assert.equal(
+    timeToPoint(0, 10, 5000, { scoring: { ...scoring, mode: "linear" } }),
+    500,
+  )
+  // Halfway through the decay period after the 2 second window
+  assert.ok(
+    timeToPoint(0, 10, 6000, { scoring: { ...scoring, mode: "fastBonus" } }) <
+      500,
+  )
+})
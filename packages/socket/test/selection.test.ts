@@ -0,0 +1,79 @@
+import { kickPlayer } from "@rahoot/socket/utils/lobby"
+import { selectAnswer } from "@rahoot/socket/utils/selection"
+import assert from "node:assert/strict"
+import { afterEach, test } from "node:test"
+import {
+  answer,
+  createTestGame,
+  received,
+  startQuestion,
+  stopGame,
+  waitFor,
+  writeGameConfig,
+} from "./helpers"
+
+afterEach(() => writeGameConfig())
+
+test("a kicked player answering is told to leave", () => {
+  const { io, game, manager, players } = createTestGame(["alice", "brian"])
+  const [alice] = players
+
+  kickPlayer(game, manager, alice.id)
+  answer(game, alice, 0)
+
+  assert.deepEqual(received(io, "alice", "game:reset"), [
+    [{ gameId: game.gameId, message: "You have been kicked by the manager" }],
+    [{ gameId: game.gameId, message: "You are no longer in this game" }],
+  ])
+  assert.deepEqual(game.round.playersAnswers, [])
+})
+
+test("stale answers can be ignored", () => {
+  writeGameConfig({ staleAnswer: "ignore" })
+
+  const { io, game, manager, players } = createTestGame(["alice", "brian"])
+  const [alice] = players
+
+  kickPlayer(game, manager, alice.id)
+  answer(game, alice, 0)
+
+  assert.equal(received(io, "alice", "game:reset").length, 1)
+  assert.equal(received(io, "alice", "game:errorMessage").length, 0)
+})
+
+test("the host answering is not treated as stale", () => {
+  const { io, game, manager } = createTestGame(["alice"])
+
+  answer(game, manager, 0)
+
+  assert.equal(received(io, "manager", "game:reset").length, 0)
+})
+
+test("an answer for another question is rejected", async () => {
+  const { io, game, players } = createTestGame(["alice", "brian"])
+  const [alice] = players
+
+  await startQuestion(game)
+  selectAnswer(game, alice, { answerIds: [0], questionIndex: 1 })
+
+  assert.deepEqual(received(io, "alice", "game:errorMessage"), [
+    ["This question is over"],
+  ])
+  assert.deepEqual(game.round.playersAnswers, [])
+
+  stopGame(game)
+})
+
+test("an answer sent after the results is dropped", async () => {
+  const { game, manager, players } = createTestGame(["alice", "brian"])
+  const [alice, brian] = players
+
+  await startQuestion(game)
+  answer(game, alice, 0)
+  game.abortRound(manager)
+  await waitFor(() => game.stats.length === 1)
+
+  answer(game, brian, 0)
+
+  assert.deepEqual(game.round.playersAnswers, [])
+})
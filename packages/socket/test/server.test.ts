@@ -0,0 +1,108 @@
+import { STATUS } from "@rahoot/common/types/game/status"
+import { createGame, createRandomGame } from "@rahoot/socket/server"
+import { showLeaderboard } from "@rahoot/socket/utils/leaderboard"
+import { joinGame } from "@rahoot/socket/utils/lobby"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import {
+  answer,
+  createServer,
+  createSocket,
+  quizz,
+  startQuestion,
+  waitFor,
+  writeQuizz,
+} from "./helpers"
+
+test("plays a two question game to the end", async () => {
+  writeQuizz("example", quizz)
+
+  const io = createServer()
+  const manager = createSocket(io, "manager")
+  const alice = createSocket(io, "alice")
+  const brian = createSocket(io, "brian")
+  const game = createGame(io, manager, "example", { seed: 1 })
+
+  assert.ok(game)
+
+  joinGame(game, alice, "alice")
+  joinGame(game, brian, "brian")
+
+  await startQuestion(game)
+  answer(game, alice, 0)
+  answer(game, brian, 1)
+  await waitFor(() => game.stats.length === 1)
+
+  showLeaderboard(game, manager)
+  game.nextRound(manager)
+
+  await waitFor(() => game.status.last?.name === STATUS.SELECT_ANSWER)
+  assert.equal(game.round.currentQuestion, 1)
+
+  answer(game, alice, 1)
+  answer(game, brian, 0)
+  await waitFor(() => game.stats.length === 2)
+
+  showLeaderboard(game, manager)
+
+  assert.equal(game.finished, true)
+  assert.equal(game.status.last?.name, STATUS.FINISHED)
+  assert.deepEqual(
+    game.leaderboard.map(({ username, correctAnswers }) => ({
+      username,
+      correctAnswers,
+    })),
+    [
+      { username: "alice", correctAnswers: 2 },
+      { username: "brian", correctAnswers: 0 },
+    ],
+  )
+  assert.ok(game.leaderboard[0].points > 0)
+  assert.equal(game.leaderboard[1].points, 0)
+})
+
+test("reports a quizz that doesn't exist", () => {
+  const io = createServer()
+  const manager = createSocket(io, "manager")
+
+  assert.equal(createGame(io, manager, "missing"), null)
+  assert.deepEqual(
+    io.emitted.filter(({ event }) => event === "game:errorMessage"),
+    [
+      {
+        target: "manager",
+        event: "game:errorMessage",
+        args: ["Quizz not found"],
+      },
+    ],
+  )
+})
+
+test("picks a random quizz among the ones with the tag", () => {
+  const io = createServer()
+  const manager = createSocket(io, "manager")
+
+  writeQuizz("example", quizz)
+  writeQuizz("music", {
+    ...quizz,
+    subject: "Music",
+    questions: quizz.questions.map((question) => ({
+      ...question,
+      tags: ["music"],
+    })),
+  })
+
+  const game = createRandomGame(io, manager, { tag: "music", seed: 7 })
+  const replay = createRandomGame(io, manager, { seed: 7 })
+  const again = createRandomGame(io, manager, { seed: 7 })
+
+  assert.equal(game?.quizz.subject, "Music")
+  assert.equal(replay?.quizz.subject, again?.quizz.subject)
+  assert.equal(createRandomGame(io, manager, { tag: "sports" }), null)
+  assert.deepEqual(
+    io.emitted
+      .filter(({ event }) => event === "manager:errorMessage")
+      .map(({ args }) => args),
+    [["No quizz with the tag sports"]],
+  )
+})
@@ -0,0 +1,64 @@
+import { playerDisconnected } from "@rahoot/socket/utils/pause"
+import { setWager } from "@rahoot/socket/utils/wager"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+import {
+  answer,
+  createSocket,
+  createTestGame,
+  quizz,
+  received,
+  startQuestion,
+  stopGame,
+  waitFor,
+} from "./helpers"
+
+const wagerQuizz = {
+  ...quizz,
+  questions: quizz.questions.map((question) => ({ ...question, wager: true })),
+}
+
+test("wagers are only taken before answering the open question", async () => {
+  const { io, game, players } = createTestGame(["alice", "brian"], {
+    quizz: wagerQuizz,
+  })
+  const [alice] = players
+  const [player] = game.players
+
+  player.points = 500
+
+  setWager(game, alice, 100)
+  await startQuestion(game)
+  setWager(game, alice, 200)
+  answer(game, alice, 0)
+  setWager(game, alice, 300)
+
+  assert.deepEqual(received(io, "alice", "player:wagerSet"), [[200]])
+  assert.equal(game.round.wagers.get(player.clientId), 200)
+
+  stopGame(game)
+})
+
+test("a wager is kept when the player reconnects", async () => {
+  const { io, game, players } = createTestGame(["alice", "brian"], {
+    quizz: wagerQuizz,
+  })
+  const [alice, brian] = players
+  const [player] = game.players
+
+  player.points = 500
+
+  await startQuestion(game)
+  setWager(game, alice, 200)
+  playerDisconnected(game, player)
+
+  const socket = createSocket(io, "alice-1", player.clientId)
+
+  game.reconnect(socket)
+  answer(game, socket, 0)
+  answer(game, brian, 1)
+  await waitFor(() => game.stats.length === 1)
+
+  // The wager plus the streak bonus of a first correct answer
+  assert.equal(player.points, 800)
+})
@@ -1,5 +1,12 @@
+// Next.js rejects a base path ending with a slash, e.g. BASE_PATH=/quiz/
+const basePath = (process.env.BASE_PATH || "").replace(/\/+$/u, "")
+
 const nextConfig = {
   output: "standalone",
+  basePath,
+  env: {
+    NEXT_PUBLIC_BASE_PATH: basePath,
+  },
   productionBrowserSourceMaps: false,
   transpilePackages: ["packages/*", "@t3-oss/env-nextjs"],
 }
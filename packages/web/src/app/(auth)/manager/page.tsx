@@ -6,11 +6,13 @@ import ManagerPassword from "@rahoot/web/components/game/create/ManagerPassword"
 import SelectQuizz from "@rahoot/web/components/game/create/SelectQuizz"
 import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
 import { useManagerStore } from "@rahoot/web/stores/manager"
+import { saveReconnectToken } from "@rahoot/web/utils/session"
 import { useRouter } from "next/navigation"
 import { useState } from "react"
+import toast from "react-hot-toast"
 
 const Manager = () => {
-  const { setGameId, setStatus } = useManagerStore()
+  const { setGameId, setStatus, setPlayers } = useManagerStore()
   const router = useRouter()
   const { socket } = useSocket()
 
@@ -22,24 +24,60 @@ const Manager = () => {
     setQuizzList(quizzList)
   })
 
-  useEvent("manager:gameCreated", ({ gameId, inviteCode }) => {
+  useEvent(
+    "manager:gameCreated",
+    ({ gameId, token, inviteCode, subject, lobbyOrder }) => {
+      saveReconnectToken(gameId, token)
+      toast.success(`Game created: ${subject}`)
+      setGameId(gameId)
+      setStatus(STATUS.SHOW_ROOM, {
+        text: "Waiting for the players",
+        inviteCode,
+        lobbyOrder,
+      })
+      router.push(`/game/manager/${gameId}`)
+    },
+  )
+
+  useEvent("manager:successReconnect", ({ gameId, token, status, players }) => {
+    saveReconnectToken(gameId, token)
     setGameId(gameId)
-    setStatus(STATUS.SHOW_ROOM, { text: "Waiting for the players", inviteCode })
+    setStatus(status.name, status.data)
+    setPlayers(players)
     router.push(`/game/manager/${gameId}`)
   })
 
   const handleAuth = (password: string) => {
+    const coHostGameId = new URLSearchParams(window.location.search).get(
+      "cohost",
+    )
+
+    if (coHostGameId) {
+      socket?.emit("manager:joinAsCoHost", { gameId: coHostGameId, password })
+
+      return
+    }
+
     socket?.emit("manager:auth", password)
   }
-  const handleCreate = (quizzId: string) => {
-    socket?.emit("game:create", quizzId)
+  const handleCreate = (quizzId: string, inviteCode?: string) => {
+    socket?.emit("game:create", quizzId, inviteCode)
+  }
+  const handleRandom = (inviteCode?: string, tag?: string) => {
+    socket?.emit("game:createRandom", inviteCode, tag)
   }
 
   if (!isAuth) {
     return <ManagerPassword onSubmit={handleAuth} />
   }
 
-  return <SelectQuizz quizzList={quizzList} onSelect={handleCreate} />
+  return (
+    <SelectQuizz
+      quizzList={quizzList}
+      onSelect={handleCreate}
+      onRandom={handleRandom}
+    />
+  )
 }
 
 export default Manager
@@ -3,8 +3,9 @@ import { NextResponse } from "next/server"
 
 export function GET() {
   return NextResponse.json({
-    webUrl: env.WEB_ORIGIN,
+    webUrl: `${env.WEB_ORIGIN}${env.BASE_PATH}`,
     socketUrl: env.SOCKET_URL,
+    socketPath: `${env.BASE_PATH}${env.WS_PATH}`,
   })
 }
 
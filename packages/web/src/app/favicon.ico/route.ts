@@ -0,0 +1,9 @@
+import env from "@rahoot/web/env"
+import { NextRequest, NextResponse } from "next/server"
+
+export function GET(request: NextRequest) {
+  return NextResponse.redirect(
+    new URL(`${env.BASE_PATH}/icon.svg`, request.url),
+    301,
+  )
+}
@@ -3,15 +3,21 @@
 import { STATUS } from "@rahoot/common/types/game/status"
 import GameWrapper from "@rahoot/web/components/game/GameWrapper"
 import Answers from "@rahoot/web/components/game/states/Answers"
+import Elimination from "@rahoot/web/components/game/states/Elimination"
 import Prepared from "@rahoot/web/components/game/states/Prepared"
 import Question from "@rahoot/web/components/game/states/Question"
 import Result from "@rahoot/web/components/game/states/Result"
+import Section from "@rahoot/web/components/game/states/Section"
 import Start from "@rahoot/web/components/game/states/Start"
 import Wait from "@rahoot/web/components/game/states/Wait"
 import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
 import { usePlayerStore } from "@rahoot/web/stores/player"
 import { useQuestionStore } from "@rahoot/web/stores/question"
 import { GAME_STATE_COMPONENTS } from "@rahoot/web/utils/constants"
+import {
+  getReconnectToken,
+  saveReconnectToken,
+} from "@rahoot/web/utils/session"
 import { useParams, useRouter } from "next/navigation"
 import toast from "react-hot-toast"
 
@@ -19,18 +25,29 @@ const Game = () => {
   const router = useRouter()
   const { socket } = useSocket()
   const { gameId: gameIdParam }: { gameId?: string } = useParams()
-  const { status, setPlayer, setGameId, setStatus, reset } = usePlayerStore()
+  const {
+    status,
+    setPlayer,
+    setGameId,
+    setStatus,
+    setLobbyPlayers,
+    setLifelines,
+    join,
+    updatePoints,
+    reset,
+  } = usePlayerStore()
   const { setQuestionStates } = useQuestionStore()
 
   useEvent("connect", () => {
     if (gameIdParam) {
-      socket?.emit("player:reconnect", { gameId: gameIdParam })
+      socket?.emit("game:reconnect", getReconnectToken(gameIdParam))
     }
   })
 
   useEvent(
     "player:successReconnect",
-    ({ gameId, status, player, currentQuestion }) => {
+    ({ gameId, token, status, player, currentQuestion }) => {
+      saveReconnectToken(gameId, token)
       setGameId(gameId)
       setStatus(status.name, status.data)
       setPlayer(player)
@@ -38,13 +55,71 @@ const Game = () => {
     },
   )
 
-  useEvent("game:status", ({ name, data }) => {
+  useEvent("game:status", ({ gameId, name, data }) => {
+    if (gameId !== gameIdParam) {
+      return
+    }
+
     if (name in GAME_STATE_COMPONENTS) {
       setStatus(name, data)
     }
+
+    if (name !== STATUS.WAIT) {
+      setLobbyPlayers([])
+    }
+  })
+
+  useEvent("game:lobbyPlayers", (data) => {
+    if (data.gameId === gameIdParam) {
+      setLobbyPlayers(data.usernames)
+    }
+  })
+
+  useEvent("player:lifelines", setLifelines)
+
+  useEvent("player:rematch", ({ gameId, keepName }) => {
+    if (gameId !== gameIdParam) {
+      return
+    }
+
+    setQuestionStates(null)
+
+    if (keepName) {
+      updatePoints(0)
+
+      return
+    }
+
+    join(gameId)
+    router.replace("/")
+  })
+
+  useEvent("player:pointsAdjusted", ({ points, formattedPoints }) => {
+    updatePoints(points, formattedPoints)
+    toast("The manager adjusted your points")
+  })
+
+  useEvent("player:message", (message) => {
+    toast(`Message from the manager: ${message}`, { duration: 8000 })
+  })
+
+  useEvent("player:muted", (muted) => {
+    if (muted) {
+      toast.error("The manager muted you, your answers won't count")
+    } else {
+      toast.success("The manager unmuted you")
+    }
+  })
+
+  useEvent("game:errorMessage", (message) => {
+    toast.error(message)
   })
 
-  useEvent("game:reset", (message) => {
+  useEvent("game:reset", ({ gameId, message }) => {
+    if (gameId && gameId !== gameIdParam) {
+      return
+    }
+
     router.replace("/")
     reset()
     setQuestionStates(null)
@@ -68,6 +143,11 @@ const Game = () => {
 
       break
 
+    case STATUS.SHOW_SECTION:
+      component = <Section data={status.data} />
+
+      break
+
     case STATUS.SHOW_PREPARED:
       component = <Prepared data={status.data} />
 
@@ -86,6 +166,11 @@ const Game = () => {
     case STATUS.SELECT_ANSWER:
       component = <Answers data={status.data} />
 
+      break
+
+    case STATUS.SHOW_ELIMINATION:
+      component = <Elimination data={status.data} />
+
       break
   }
 
@@ -1,19 +1,26 @@
 "use client"
 
 import { STATUS } from "@rahoot/common/types/game/status"
+import GameReview from "@rahoot/web/components/game/GameReview"
 import GameWrapper from "@rahoot/web/components/game/GameWrapper"
 import Answers from "@rahoot/web/components/game/states/Answers"
+import Elimination from "@rahoot/web/components/game/states/Elimination"
 import Leaderboard from "@rahoot/web/components/game/states/Leaderboard"
 import Podium from "@rahoot/web/components/game/states/Podium"
 import Prepared from "@rahoot/web/components/game/states/Prepared"
 import Question from "@rahoot/web/components/game/states/Question"
 import Responses from "@rahoot/web/components/game/states/Responses"
 import Room from "@rahoot/web/components/game/states/Room"
+import Section from "@rahoot/web/components/game/states/Section"
 import Start from "@rahoot/web/components/game/states/Start"
 import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
 import { useManagerStore } from "@rahoot/web/stores/manager"
 import { useQuestionStore } from "@rahoot/web/stores/question"
 import { GAME_STATE_COMPONENTS_MANAGER } from "@rahoot/web/utils/constants"
+import {
+  getReconnectToken,
+  saveReconnectToken,
+} from "@rahoot/web/utils/session"
 import { useParams, useRouter } from "next/navigation"
 import toast from "react-hot-toast"
 
@@ -21,11 +28,25 @@ const ManagerGame = () => {
   const router = useRouter()
   const { gameId: gameIdParam }: { gameId?: string } = useParams()
   const { socket } = useSocket()
-  const { gameId, status, setGameId, setStatus, setPlayers, reset } =
-    useManagerStore()
+  const {
+    gameId,
+    status,
+    setGameId,
+    setStatus,
+    setPlayers,
+    updatePlayer,
+    setOutline,
+    review,
+    setReview,
+    reset,
+  } = useManagerStore()
   const { setQuestionStates } = useQuestionStore()
 
-  useEvent("game:status", ({ name, data }) => {
+  useEvent("game:status", ({ gameId, name, data }) => {
+    if (gameId !== gameIdParam) {
+      return
+    }
+
     if (name in GAME_STATE_COMPONENTS_MANAGER) {
       setStatus(name, data)
     }
@@ -33,13 +54,14 @@ const ManagerGame = () => {
 
   useEvent("connect", () => {
     if (gameIdParam) {
-      socket?.emit("manager:reconnect", { gameId: gameIdParam })
+      socket?.emit("game:reconnect", getReconnectToken(gameIdParam))
     }
   })
 
   useEvent(
     "manager:successReconnect",
-    ({ gameId, status, players, currentQuestion }) => {
+    ({ gameId, token, status, players, currentQuestion }) => {
+      saveReconnectToken(gameId, token)
       setGameId(gameId)
       setStatus(status.name, status.data)
       setPlayers(players)
@@ -47,7 +69,45 @@ const ManagerGame = () => {
     },
   )
 
-  useEvent("game:reset", (message) => {
+  useEvent("manager:quizzOutline", (titles) => {
+    setOutline(titles)
+  })
+
+  useEvent("manager:gameReview", (questions) => {
+    setReview(questions)
+  })
+
+  useEvent("manager:playerReconnected", ({ oldId, player }) => {
+    updatePlayer(oldId, player)
+    toast.success(`${player.username} reconnected`)
+  })
+
+  useEvent("manager:playerDisconnected", (player) => {
+    updatePlayer(player.id, { connected: false })
+    toast.error(`${player.username} disconnected`)
+  })
+
+  useEvent("manager:allPlayersDisconnected", ({ action, timeout }) => {
+    toast.error(
+      action === "pause"
+        ? "All players disconnected, the game is paused until one comes back"
+        : `All players disconnected, the game ends in ${timeout}s unless one comes back`,
+    )
+  })
+
+  useEvent("manager:capacityWarning", ({ count, maxPlayers, threshold }) => {
+    toast(`Lobby at ${threshold}% capacity (${count}/${maxPlayers} players)`)
+  })
+
+  useEvent("manager:errorMessage", (message) => {
+    toast.error(message)
+  })
+
+  useEvent("game:reset", ({ gameId, message }) => {
+    if (gameId && gameId !== gameIdParam) {
+      return
+    }
+
     router.replace("/manager")
     reset()
     setQuestionStates(null)
@@ -65,11 +125,21 @@ const ManagerGame = () => {
 
         break
 
+      case STATUS.SHOW_SECTION:
+        socket?.emit("manager:nextQuestion", { gameId })
+
+        break
+
       case STATUS.SELECT_ANSWER:
         socket?.emit("manager:abortQuiz", { gameId })
 
         break
 
+      case STATUS.SHOW_ELIMINATION:
+        socket?.emit("manager:eliminateAnswer", { gameId })
+
+        break
+
       case STATUS.SHOW_RESPONSES:
         socket?.emit("manager:showLeaderboard", { gameId })
 
@@ -78,10 +148,26 @@ const ManagerGame = () => {
       case STATUS.SHOW_LEADERBOARD:
         socket?.emit("manager:nextQuestion", { gameId })
 
+        break
+
+      case STATUS.FINISHED:
+        socket?.emit("manager:rematch", { gameId })
+
         break
     }
   }
 
+  const handleRedo = () => {
+    if (
+      !gameId ||
+      !window.confirm("Start this question over? Its answers will be lost.")
+    ) {
+      return
+    }
+
+    socket?.emit("manager:redoQuestion", { gameId })
+  }
+
   let component = null
 
   switch (status?.name) {
@@ -95,6 +181,11 @@ const ManagerGame = () => {
 
       break
 
+    case STATUS.SHOW_SECTION:
+      component = <Section data={status.data} />
+
+      break
+
     case STATUS.SHOW_PREPARED:
       component = <Prepared data={status.data} />
 
@@ -110,6 +201,11 @@ const ManagerGame = () => {
 
       break
 
+    case STATUS.SHOW_ELIMINATION:
+      component = <Elimination data={status.data} />
+
+      break
+
     case STATUS.SHOW_RESPONSES:
       component = <Responses data={status.data} />
 
@@ -121,13 +217,23 @@ const ManagerGame = () => {
       break
 
     case STATUS.FINISHED:
-      component = <Podium data={status.data} />
+      component = (
+        <>
+          <Podium data={status.data} />
+          {review.length > 0 && <GameReview review={review} />}
+        </>
+      )
 
       break
   }
 
   return (
-    <GameWrapper statusName={status?.name} onNext={handleSkip} manager>
+    <GameWrapper
+      statusName={status?.name}
+      onNext={handleSkip}
+      onRedo={handleRedo}
+      manager
+    >
       {component}
     </GameWrapper>
   )
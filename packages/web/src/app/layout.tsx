@@ -1,5 +1,6 @@
 import Toaster from "@rahoot/web/components/Toaster"
 import { SocketProvider } from "@rahoot/web/contexts/socketProvider"
+import { BASE_PATH } from "@rahoot/web/utils/constants"
 import type { Metadata } from "next"
 import { Montserrat } from "next/font/google"
 import { PropsWithChildren } from "react"
@@ -12,7 +13,8 @@ const montserrat = Montserrat({
 
 export const metadata: Metadata = {
   title: "Rahoot !",
-  icons: "/icon.svg",
+  icons: `${BASE_PATH}/icon.svg`,
+  manifest: `${BASE_PATH}/manifest.json`,
 }
 
 const RootLayout = ({ children }: PropsWithChildren) => (
@@ -0,0 +1,22 @@
+import env from "@rahoot/web/env"
+import { NextResponse } from "next/server"
+
+export function GET() {
+  return NextResponse.json({
+    name: env.APP_NAME,
+    short_name: env.APP_NAME,
+    start_url: `${env.BASE_PATH}/`,
+    display: "standalone",
+    background_color: "#1a140b",
+    theme_color: env.THEME_COLOR,
+    icons: [
+      {
+        src: `${env.BASE_PATH}/icon.svg`,
+        sizes: "any",
+        type: "image/svg+xml",
+      },
+    ],
+  })
+}
+
+export const dynamic = "force-dynamic"
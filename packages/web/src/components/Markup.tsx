@@ -0,0 +1,10 @@
+type Props = {
+  html: string
+}
+
+// Quiz text is escaped or sanitized by the socket server when loaded
+const Markup = ({ html }: Props) => (
+  <span dangerouslySetInnerHTML={{ __html: html }} />
+)
+
+export default Markup
@@ -0,0 +1,27 @@
+import { Confidence, CONFIDENCE_LEVELS } from "@rahoot/common/types/game"
+import clsx from "clsx"
+
+type Props = {
+  value: Confidence | null
+  onChange: (_confidence: Confidence | null) => void
+}
+
+const ConfidencePicker = ({ value, onChange }: Props) => (
+  <div className="mx-auto mb-4 flex w-full max-w-7xl flex-wrap items-center justify-center gap-2 px-2">
+    <span className="font-bold text-white drop-shadow-md">Confidence</span>
+    {CONFIDENCE_LEVELS.map((level) => (
+      <button
+        key={level}
+        className={clsx(
+          "rounded-md px-3 py-1 font-bold text-white capitalize",
+          value === level ? "bg-primary" : "bg-black/40",
+        )}
+        onClick={() => onChange(value === level ? null : level)}
+      >
+        {level}
+      </button>
+    ))}
+  </div>
+)
+
+export default ConfidencePicker
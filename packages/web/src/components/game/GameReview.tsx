@@ -0,0 +1,89 @@
+"use client"
+
+import { QuestionReview } from "@rahoot/common/types/game"
+import Markup from "@rahoot/web/components/Markup"
+import { ANSWERS_COLORS } from "@rahoot/web/utils/constants"
+import { calculatePercentages } from "@rahoot/web/utils/score"
+import clsx from "clsx"
+import { useState } from "react"
+
+type Props = {
+  review: QuestionReview[]
+}
+
+const GameReview = ({ review }: Props) => {
+  const [index, setIndex] = useState<number | null>(null)
+
+  if (index === null) {
+    return (
+      <button
+        className="fixed bottom-4 left-1/2 z-40 -translate-x-1/2 rounded-md bg-white px-4 py-2 text-lg font-bold text-black shadow-lg"
+        onClick={() => setIndex(0)}
+      >
+        Review answers
+      </button>
+    )
+  }
+
+  const question = review[index]
+  const solutions = [question.solution].flat()
+  const percentages = calculatePercentages(question.responses)
+
+  return (
+    <div className="fixed inset-0 z-50 flex flex-col items-center justify-center gap-6 bg-black/80 p-4 text-white">
+      <p className="text-lg font-bold">
+        {`Question ${index + 1} / ${review.length}`}
+      </p>
+      <h2 className="text-center text-2xl font-bold md:text-4xl">
+        <Markup html={question.question} />
+      </h2>
+      <p className="text-lg">
+        {`${question.correctCount} of ${question.answered} players answered correctly`}
+      </p>
+
+      <ul className="flex w-full max-w-3xl flex-col gap-2">
+        {question.answers.map((answer, key) => (
+          <li
+            key={key}
+            className={clsx(
+              "flex items-center justify-between rounded-md px-4 py-2 text-lg font-bold",
+              ANSWERS_COLORS[key],
+              { "opacity-50": !solutions.includes(key) },
+            )}
+          >
+            <span>
+              {solutions.includes(key) && "✓ "}
+              <Markup html={answer} />
+            </span>
+            <span>{`${question.responses[key]} (${percentages[key]})`}</span>
+          </li>
+        ))}
+      </ul>
+
+      <div className="flex gap-4">
+        <button
+          className="rounded-md bg-white px-4 py-2 font-bold text-black disabled:opacity-50"
+          disabled={index === 0}
+          onClick={() => setIndex(index - 1)}
+        >
+          Previous
+        </button>
+        <button
+          className="rounded-md bg-white px-4 py-2 font-bold text-black"
+          onClick={() => setIndex(null)}
+        >
+          Close
+        </button>
+        <button
+          className="rounded-md bg-white px-4 py-2 font-bold text-black disabled:opacity-50"
+          disabled={index === review.length - 1}
+          onClick={() => setIndex(index + 1)}
+        >
+          Next
+        </button>
+      </div>
+    </div>
+  )
+}
+
+export default GameReview
@@ -1,31 +1,57 @@
 "use client"
 
-import { Status } from "@rahoot/common/types/game/status"
+import { Status, STATUS } from "@rahoot/common/types/game/status"
 import background from "@rahoot/web/assets/background.webp"
 import Button from "@rahoot/web/components/Button"
+import QuizzOutline from "@rahoot/web/components/game/QuizzOutline"
 import Loader from "@rahoot/web/components/Loader"
 import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
+import { useManagerStore } from "@rahoot/web/stores/manager"
 import { usePlayerStore } from "@rahoot/web/stores/player"
 import { useQuestionStore } from "@rahoot/web/stores/question"
 import { MANAGER_SKIP_BTN } from "@rahoot/web/utils/constants"
 import clsx from "clsx"
 import Image from "next/image"
+import { useParams } from "next/navigation"
 import { PropsWithChildren, useEffect, useState } from "react"
 
+// Steps of a question that can be started over, until the leaderboard is shown
+const REDO_STATUSES: Status[] = [
+  STATUS.SHOW_PREPARED,
+  STATUS.SHOW_QUESTION,
+  STATUS.SELECT_ANSWER,
+  STATUS.SHOW_ELIMINATION,
+  STATUS.SHOW_RESPONSES,
+]
+
 type Props = PropsWithChildren & {
   statusName: Status | undefined
   onNext?: () => void
+  onRedo?: () => void
   manager?: boolean
 }
 
-const GameWrapper = ({ children, statusName, onNext, manager }: Props) => {
+const GameWrapper = ({
+  children,
+  statusName,
+  onNext,
+  onRedo,
+  manager,
+}: Props) => {
+  const { gameId: gameIdParam }: { gameId?: string } = useParams()
   const { isConnected } = useSocket()
   const { player } = usePlayerStore()
+  const { outline } = useManagerStore()
   const { questionStates, setQuestionStates } = useQuestionStore()
   const [isDisabled, setIsDisabled] = useState(false)
   const next = statusName ? MANAGER_SKIP_BTN[statusName] : null
+  const canRedo = statusName ? REDO_STATUSES.includes(statusName) : false
+
+  useEvent("game:updateQuestion", ({ gameId, current, total }) => {
+    if (gameId !== gameIdParam) {
+      return
+    }
 
-  useEvent("game:updateQuestion", ({ current, total }) => {
     setQuestionStates({
       current,
       total,
@@ -59,21 +85,39 @@ const GameWrapper = ({ children, statusName, onNext, manager }: Props) => {
       ) : (
         <>
           <div className="flex w-full justify-between p-4">
-            {questionStates && (
+            {questionStates && manager && outline.length > 0 && (
+              <QuizzOutline questionStates={questionStates} />
+            )}
+
+            {questionStates && !(manager && outline.length > 0) && (
               <div className="shadow-inset flex items-center rounded-md bg-white p-2 px-4 text-lg font-bold text-black">
-                {`${questionStates.current} / ${questionStates.total}`}
+                {questionStates.current > 0
+                  ? `${questionStates.current} / ${questionStates.total}`
+                  : "Warmup"}
               </div>
             )}
 
-            {manager && next && (
-              <Button
-                className={clsx("self-end bg-white px-4 text-black!", {
-                  "pointer-events-none": isDisabled,
-                })}
-                onClick={handleNext}
-              >
-                {next}
-              </Button>
+            {manager && (next || canRedo) && (
+              <div className="flex gap-2 self-end">
+                {canRedo && onRedo && (
+                  <Button
+                    className="bg-white/80 px-4 text-black!"
+                    onClick={onRedo}
+                  >
+                    Redo question
+                  </Button>
+                )}
+                {next && (
+                  <Button
+                    className={clsx("bg-white px-4 text-black!", {
+                      "pointer-events-none": isDisabled,
+                    })}
+                    onClick={handleNext}
+                  >
+                    {next}
+                  </Button>
+                )}
+              </div>
             )}
           </div>
 
@@ -83,7 +127,7 @@ const GameWrapper = ({ children, statusName, onNext, manager }: Props) => {
             <div className="z-50 flex items-center justify-between bg-white px-4 py-2 text-lg font-bold text-white">
               <p className="text-gray-800">{player?.username}</p>
               <div className="rounded-sm bg-gray-800 px-3 py-1 text-lg">
-                {player?.points}
+                {player?.formattedPoints ?? player?.points}
               </div>
             </div>
           )}
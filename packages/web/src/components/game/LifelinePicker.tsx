@@ -0,0 +1,48 @@
+"use client"
+
+import { Lifeline, LIFELINES } from "@rahoot/common/types/game"
+import { useSocket } from "@rahoot/web/contexts/socketProvider"
+import { usePlayerStore } from "@rahoot/web/stores/player"
+import { useParams } from "next/navigation"
+
+const LIFELINE_LABELS: Record<Lifeline, string> = {
+  fiftyFifty: "50/50",
+  extraTime: "Extra time",
+}
+
+const LifelinePicker = () => {
+  const { gameId }: { gameId?: string } = useParams()
+  const { socket } = useSocket()
+  const { lifelines } = usePlayerStore()
+
+  const available = LIFELINES.filter((kind) => (lifelines?.[kind] || 0) > 0)
+
+  if (available.length === 0) {
+    return null
+  }
+
+  const handleLifeline = (kind: Lifeline) => () => {
+    socket?.emit("player:useLifeline", {
+      gameId,
+      data: {
+        kind,
+      },
+    })
+  }
+
+  return (
+    <div className="mx-auto mb-4 flex w-full max-w-7xl flex-wrap items-center justify-center gap-2 px-2">
+      {available.map((kind) => (
+        <button
+          key={kind}
+          className="rounded-md bg-black/40 px-3 py-1 font-bold text-white"
+          onClick={handleLifeline(kind)}
+        >
+          {`${LIFELINE_LABELS[kind]} (${lifelines?.[kind]})`}
+        </button>
+      ))}
+    </div>
+  )
+}
+
+export default LifelinePicker
@@ -0,0 +1,37 @@
+"use client"
+
+import { GameUpdateQuestion } from "@rahoot/common/types/game"
+import Markup from "@rahoot/web/components/Markup"
+import { useManagerStore } from "@rahoot/web/stores/manager"
+import clsx from "clsx"
+
+type Props = {
+  questionStates: GameUpdateQuestion
+}
+
+const QuizzOutline = ({ questionStates: { current, total } }: Props) => {
+  const { outline } = useManagerStore()
+
+  return (
+    <details className="relative">
+      <summary className="shadow-inset flex cursor-pointer list-none items-center rounded-md bg-white p-2 px-4 text-lg font-bold text-black">
+        {current > 0 ? `${current} / ${total}` : "Warmup"}
+      </summary>
+      <ol className="absolute top-full left-0 z-50 mt-2 max-h-96 w-80 overflow-y-auto rounded-md bg-white p-2 shadow-lg">
+        {outline.map((title, key) => (
+          <li
+            key={key}
+            className={clsx("truncate rounded-sm px-2 py-1 text-black", {
+              "bg-primary font-bold text-white": key + 1 === current,
+            })}
+          >
+            {`${key + 1}. `}
+            <Markup html={title} />
+          </li>
+        ))}
+      </ol>
+    </details>
+  )
+}
+
+export default QuizzOutline
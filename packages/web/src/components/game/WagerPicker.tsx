@@ -0,0 +1,57 @@
+"use client"
+
+import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
+import { usePlayerStore } from "@rahoot/web/stores/player"
+import clsx from "clsx"
+import { useParams } from "next/navigation"
+import { useState } from "react"
+
+const WAGER_RATIOS = [0, 0.25, 0.5, 1]
+
+const WagerPicker = () => {
+  const { gameId }: { gameId?: string } = useParams()
+  const { socket } = useSocket()
+  const { player } = usePlayerStore()
+  const [wager, setWager] = useState(0)
+
+  const points = player?.points || 0
+
+  const handleWager = (amount: number) => () => {
+    socket?.emit("player:setWager", {
+      gameId,
+      data: {
+        amount,
+      },
+    })
+  }
+
+  useEvent("player:wagerSet", (amount) => {
+    setWager(amount)
+  })
+
+  return (
+    <div className="mx-auto mb-4 flex w-full max-w-7xl flex-wrap items-center justify-center gap-2 px-2">
+      <span className="font-bold text-white drop-shadow-md">
+        Double or nothing
+      </span>
+      {WAGER_RATIOS.map((ratio) => {
+        const amount = Math.floor(points * ratio)
+
+        return (
+          <button
+            key={ratio}
+            className={clsx(
+              "rounded-md px-3 py-1 font-bold text-white",
+              wager === amount ? "bg-primary" : "bg-black/40",
+            )}
+            onClick={handleWager(amount)}
+          >
+            {ratio === 1 ? `All in (${amount})` : amount}
+          </button>
+        )
+      })}
+    </div>
+  )
+}
+
+export default WagerPicker
@@ -1,16 +1,32 @@
 import { QuizzWithId } from "@rahoot/common/types/game"
 import Button from "@rahoot/web/components/Button"
+import Input from "@rahoot/web/components/Input"
+import { useEvent } from "@rahoot/web/contexts/socketProvider"
 import clsx from "clsx"
 import { useState } from "react"
 import toast from "react-hot-toast"
 
 type Props = {
   quizzList: QuizzWithId[]
-  onSelect: (_id: string) => void
+  onSelect: (_id: string, _inviteCode?: string) => void
+  onRandom: (_inviteCode?: string, _tag?: string) => void
 }
 
-const SelectQuizz = ({ quizzList, onSelect }: Props) => {
+const SelectQuizz = ({ quizzList, onSelect, onRandom }: Props) => {
   const [selected, setSelected] = useState<string | null>(null)
+  const [inviteCode, setInviteCode] = useState("")
+  const [tag, setTag] = useState("")
+  const tags = [
+    ...new Set(
+      quizzList.flatMap((quizz) =>
+        quizz.questions.flatMap((question) => question.tags ?? []),
+      ),
+    ),
+  ].sort()
+
+  useEvent("manager:errorMessage", (message) => {
+    toast.error(message)
+  })
 
   const handleSelect = (id: string) => () => {
     if (selected === id) {
@@ -27,7 +43,7 @@ const SelectQuizz = ({ quizzList, onSelect }: Props) => {
       return
     }
 
-    onSelect(selected)
+    onSelect(selected, inviteCode || undefined)
   }
 
   return (
@@ -56,7 +72,31 @@ const SelectQuizz = ({ quizzList, onSelect }: Props) => {
           ))}
         </div>
       </div>
+      <Input
+        onChange={(e) => setInviteCode(e.target.value)}
+        placeholder="Custom PIN (optional)"
+      />
       <Button onClick={handleSubmit}>Submit</Button>
+      {tags.length > 0 && (
+        <select
+          className="rounded-sm p-2 text-sm font-semibold outline-2 outline-gray-300"
+          value={tag}
+          onChange={(e) => setTag(e.target.value)}
+        >
+          <option value="">Random quizz on any topic</option>
+          {tags.map((name) => (
+            <option key={name} value={name}>
+              Random quizz about {name}
+            </option>
+          ))}
+        </select>
+      )}
+      <button
+        className="text-sm font-semibold text-gray-500 underline"
+        onClick={() => onRandom(inviteCode || undefined, tag || undefined)}
+      >
+        Surprise me with a random quizz
+      </button>
     </div>
   )
 }
@@ -6,13 +6,14 @@ import Form from "@rahoot/web/components/Form"
 import Input from "@rahoot/web/components/Input"
 import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
 import { usePlayerStore } from "@rahoot/web/stores/player"
+import { saveReconnectToken } from "@rahoot/web/utils/session"
 
 import { useRouter } from "next/navigation"
 import { KeyboardEvent, useState } from "react"
 
 const Username = () => {
   const { socket } = useSocket()
-  const { gameId, login, setStatus } = usePlayerStore()
+  const { gameId, login, setStatus, setLobbyPlayers } = usePlayerStore()
   const router = useRouter()
   const [username, setUsername] = useState("")
 
@@ -21,7 +22,10 @@ const Username = () => {
       return
     }
 
-    socket?.emit("player:login", { gameId, data: { username } })
+    socket?.emit("player:login", {
+      gameId,
+      data: { username, locale: navigator.language },
+    })
   }
 
   const handleKeyDown = (event: KeyboardEvent) => {
@@ -30,7 +34,14 @@ const Username = () => {
     }
   }
 
-  useEvent("game:successJoin", (gameId) => {
+  useEvent("game:lobbyPlayers", (data) => {
+    if (data.gameId === gameId) {
+      setLobbyPlayers(data.usernames)
+    }
+  })
+
+  useEvent("game:successJoin", ({ gameId, token }) => {
+    saveReconnectToken(gameId, token)
     setStatus(STATUS.WAIT, { text: "Waiting for the players" })
     login(username)
 
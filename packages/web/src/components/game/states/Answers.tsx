@@ -1,7 +1,12 @@
 "use client"
 
+import { Confidence } from "@rahoot/common/types/game"
 import { CommonStatusDataMap } from "@rahoot/common/types/game/status"
 import AnswerButton from "@rahoot/web/components/AnswerButton"
+import ConfidencePicker from "@rahoot/web/components/game/ConfidencePicker"
+import LifelinePicker from "@rahoot/web/components/game/LifelinePicker"
+import WagerPicker from "@rahoot/web/components/game/WagerPicker"
+import Markup from "@rahoot/web/components/Markup"
 import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
 import { usePlayerStore } from "@rahoot/web/stores/player"
 import {
@@ -10,9 +15,13 @@ import {
   SFX_ANSWERS_MUSIC,
   SFX_ANSWERS_SOUND,
 } from "@rahoot/web/utils/constants"
+import { toPlainText } from "@rahoot/web/utils/markup"
+import { resolveMediaUrl } from "@rahoot/web/utils/media"
+import { formatNumber } from "@rahoot/web/utils/number"
 import clsx from "clsx"
 import { useParams } from "next/navigation"
-import { useEffect, useState } from "react"
+import { FormEvent, useEffect, useState } from "react"
+import toast from "react-hot-toast"
 import useSound from "use-sound"
 
 type Props = {
@@ -20,7 +29,25 @@ type Props = {
 }
 
 const Answers = ({
-  data: { question, answers, image, audio, video, time, totalPlayer },
+  data: {
+    question,
+    section,
+    answers,
+    image,
+    audio,
+    video,
+    media,
+    wager,
+    time,
+    lockTime,
+    askConfidence,
+    totalPlayer,
+    validKeys,
+    multiple,
+    numeric,
+    allowAnswerChange,
+    questionIndex,
+  },
 }: Props) => {
   const { gameId }: { gameId?: string } = useParams()
   const { socket } = useSocket()
@@ -28,6 +55,14 @@ const Answers = ({
 
   const [cooldown, setCooldown] = useState(time)
   const [totalAnswer, setTotalAnswer] = useState(0)
+  const [totalPlayers, setTotalPlayers] = useState(totalPlayer)
+  const [answererNames, setAnswererNames] = useState<string[]>([])
+  const [isLocked, setIsLocked] = useState(lockTime > 0)
+  const [pausedFor, setPausedFor] = useState<string | null>(null)
+  const [confidence, setConfidence] = useState<Confidence | null>(null)
+  const [selected, setSelected] = useState<number[]>([])
+  const [value, setValue] = useState("")
+  const [submitted, setSubmitted] = useState(false)
 
   const [sfxPop] = useSound(SFX_ANSWERS_SOUND, {
     volume: 0.1,
@@ -39,18 +74,85 @@ const Answers = ({
     loop: true,
   })
 
+  const canSubmit =
+    multiple !== undefined &&
+    selected.length >= multiple.min &&
+    selected.length <= multiple.max
+
+  const submitAnswer = (answerKeys: number[]) => {
+    socket?.emit("player:selectedAnswer", {
+      gameId,
+      data: {
+        answerKeys,
+        confidence: confidence || undefined,
+        questionIndex,
+      },
+    })
+    sfxPop()
+
+    // The answers stay on screen, the picked ones highlighted
+    if (allowAnswerChange) {
+      setSelected(answerKeys)
+      setSubmitted(true)
+    }
+  }
+
   const handleAnswer = (answerKey: number) => () => {
-    if (!player) {
+    if (
+      !player ||
+      isLocked ||
+      pausedFor ||
+      !validKeys.includes(answerKey)
+    ) {
       return
     }
 
-    socket?.emit("player:selectedAnswer", {
+    if (!multiple) {
+      submitAnswer([answerKey])
+
+      return
+    }
+
+    if (selected.includes(answerKey)) {
+      setSelected(selected.filter((key) => key !== answerKey))
+    } else if (selected.length < multiple.max) {
+      setSelected([...selected, answerKey])
+    }
+  }
+
+  const handleNumericSubmit = (event: FormEvent) => {
+    event.preventDefault()
+
+    const number = Number(value.replace(",", "."))
+
+    if (!player || isLocked || pausedFor || !value.trim()) {
+      return
+    }
+
+    if (!Number.isFinite(number)) {
+      toast.error("Enter a number")
+
+      return
+    }
+
+    socket?.emit("player:submitNumeric", {
       gameId,
       data: {
-        answerKey,
+        value: number,
+        confidence: confidence || undefined,
+        questionIndex,
       },
     })
     sfxPop()
+    setSubmitted(true)
+  }
+
+  const handleSubmit = () => {
+    if (!canSubmit || isLocked || pausedFor) {
+      return
+    }
+
+    submitAnswer(selected)
   }
 
   useEffect(() => {
@@ -66,26 +168,87 @@ const Answers = ({
     }
   }, [playMusic])
 
-  useEvent("game:cooldown", (sec) => {
-    setCooldown(sec)
+  useEffect(() => {
+    if (lockTime <= 0) {
+      return
+    }
+
+    const timer = setTimeout(() => {
+      setIsLocked(false)
+    }, lockTime * 1000)
+
+    // eslint-disable-next-line consistent-return
+    return () => {
+      clearTimeout(timer)
+    }
+  }, [lockTime])
+
+  useEvent("game:answersUnlocked", (data) => {
+    if (data.gameId === gameId) {
+      setIsLocked(false)
+    }
+  })
+
+  useEvent("game:paused", (data) => {
+    if (data.gameId === gameId) {
+      setPausedFor(data.username)
+    }
+  })
+
+  useEvent("game:resumed", (data) => {
+    if (data.gameId === gameId) {
+      setPausedFor(null)
+    }
+  })
+
+  useEvent("game:cooldown", (data) => {
+    if (data.gameId === gameId) {
+      setCooldown(data.count)
+    }
   })
 
-  useEvent("game:playerAnswer", (count) => {
-    setTotalAnswer(count)
+  useEvent("game:playerAnswer", (data) => {
+    if (data.gameId !== gameId) {
+      return
+    }
+
+    setTotalAnswer(data.count)
     sfxPop()
   })
 
+  useEvent("game:totalPlayers", (data) => {
+    if (data.gameId === gameId) {
+      setTotalPlayers(data.count)
+    }
+  })
+
+  useEvent("manager:playerAnswered", (username) => {
+    setAnswererNames((names) => [...names, username])
+  })
+
   return (
     <div className="flex h-full flex-1 flex-col justify-between">
+      {pausedFor && (
+        <div className="fixed inset-0 z-20 flex items-center justify-center bg-black/60 px-4">
+          <p className="text-center text-3xl font-bold text-white drop-shadow-lg">
+            Game paused, waiting for {pausedFor} to reconnect
+          </p>
+        </div>
+      )}
       <div className="mx-auto inline-flex h-full w-full max-w-7xl flex-1 flex-col items-center justify-center gap-5">
+        {section && (
+          <p className="rounded-md bg-black/40 px-4 py-1 text-lg font-bold text-white">
+            {section}
+          </p>
+        )}
         <h2 className="text-center text-2xl font-bold text-white drop-shadow-lg md:text-4xl lg:text-5xl">
-          {question}
+          <Markup html={question} />
         </h2>
 
         {Boolean(audio) && !player && (
           <audio
             className="m-4 mb-2 w-auto rounded-md"
-            src={audio}
+            src={resolveMediaUrl(audio)}
             autoPlay
             controls
           />
@@ -94,7 +257,9 @@ const Answers = ({
         {Boolean(video) && !player && (
           <video
             className="m-4 mb-2 aspect-video max-h-60 w-auto rounded-md px-4 sm:max-h-100"
-            src={video}
+            src={resolveMediaUrl(video)}
+            width={media?.video?.width}
+            height={media?.video?.height}
             autoPlay
             controls
           />
@@ -102,23 +267,46 @@ const Answers = ({
 
         {Boolean(image) && (
           <img
-            alt={question}
-            src={image}
+            alt={toPlainText(question)}
+            src={resolveMediaUrl(image)}
+            width={media?.image?.width}
+            height={media?.image?.height}
             className="mb-2 max-h-60 w-auto rounded-md px-4 sm:max-h-100"
           />
         )}
       </div>
 
       <div>
+        {answererNames.length > 0 && (
+          <div className="mx-auto mb-4 flex w-full max-w-7xl flex-wrap justify-center gap-2 px-2">
+            {answererNames.map((username, key) => (
+              <span
+                key={key}
+                className="anim-show rounded-md bg-black/40 px-3 py-1 font-bold text-white"
+              >
+                {username}
+              </span>
+            ))}
+          </div>
+        )}
+
+        {wager && player && <WagerPicker />}
+
+        {player && <LifelinePicker />}
+
+        {askConfidence && !wager && player && (
+          <ConfidencePicker value={confidence} onChange={setConfidence} />
+        )}
+
         <div className="mx-auto mb-4 flex w-full max-w-7xl justify-between gap-1 px-2 text-lg font-bold text-white md:text-xl">
           <div className="flex flex-col items-center rounded-full bg-black/40 px-4 text-lg font-bold">
             <span className="translate-y-1 text-sm">Time</span>
-            <span>{cooldown}</span>
+            <span>{formatNumber(cooldown)}</span>
           </div>
           <div className="flex flex-col items-center rounded-full bg-black/40 px-4 text-lg font-bold">
             <span className="translate-y-1 text-sm">Answers</span>
             <span>
-              {totalAnswer}/{totalPlayer}
+              {totalAnswer}/{totalPlayers}
             </span>
           </div>
         </div>
@@ -127,14 +315,75 @@ const Answers = ({
           {answers.map((answer, key) => (
             <AnswerButton
               key={key}
-              className={clsx(ANSWERS_COLORS[key])}
+              className={clsx(ANSWERS_COLORS[key], {
+                "opacity-65": isLocked || pausedFor,
+                "opacity-25 grayscale": !validKeys.includes(key),
+                "ring-4 ring-white": selected.includes(key),
+              })}
               icon={ANSWERS_ICONS[key]}
               onClick={handleAnswer(key)}
             >
-              {answer}
+              <Markup html={answer} />
             </AnswerButton>
           ))}
         </div>
+
+        {numeric && player && (
+          <form
+            className="mx-auto mb-4 flex w-full max-w-md gap-2 px-2"
+            onSubmit={handleNumericSubmit}
+          >
+            <input
+              className="flex-1 rounded-md bg-white px-4 py-2 text-lg font-bold text-black"
+              inputMode="decimal"
+              placeholder="Your answer"
+              value={value}
+              onChange={(event) => setValue(event.target.value)}
+              disabled={isLocked || Boolean(pausedFor)}
+            />
+            <button
+              className={clsx(
+                "rounded-md px-4 py-2 font-bold text-white",
+                value.trim() ? "bg-primary" : "bg-black/40",
+              )}
+              type="submit"
+            >
+              Submit
+            </button>
+          </form>
+        )}
+
+        {submitted && allowAnswerChange && (
+          <p className="mx-auto mb-4 text-center font-bold text-white drop-shadow-md">
+            Answer saved, you can change it until the time is up
+          </p>
+        )}
+
+        {numeric && !player && (
+          <p className="mx-auto mb-4 text-center text-2xl font-bold text-white drop-shadow-lg">
+            Type your answer on your device
+          </p>
+        )}
+
+        {multiple && player && (
+          <div className="mx-auto mb-4 flex w-full max-w-7xl items-center justify-center gap-4 px-2">
+            <span className="font-bold text-white drop-shadow-md">
+              {multiple.min === multiple.max
+                ? `Pick ${multiple.min}`
+                : `Pick ${multiple.min} to ${multiple.max}`}
+            </span>
+            <button
+              className={clsx(
+                "rounded-md px-4 py-2 font-bold text-white",
+                canSubmit ? "bg-primary" : "bg-black/40",
+              )}
+              disabled={!canSubmit}
+              onClick={handleSubmit}
+            >
+              {`Submit (${selected.length})`}
+            </button>
+          </div>
+        )}
       </div>
     </div>
   )
@@ -0,0 +1,48 @@
+import { CommonStatusDataMap } from "@rahoot/common/types/game/status"
+import AnswerButton from "@rahoot/web/components/AnswerButton"
+import Markup from "@rahoot/web/components/Markup"
+import { ANSWERS_COLORS, ANSWERS_ICONS } from "@rahoot/web/utils/constants"
+import { toPlainText } from "@rahoot/web/utils/markup"
+import { resolveMediaUrl } from "@rahoot/web/utils/media"
+import clsx from "clsx"
+
+type Props = {
+  data: CommonStatusDataMap["SHOW_ELIMINATION"]
+}
+
+const Elimination = ({
+  data: { question, answers, image, eliminated },
+}: Props) => (
+  <div className="flex h-full flex-1 flex-col justify-between">
+    <div className="mx-auto inline-flex h-full w-full max-w-7xl flex-1 flex-col items-center justify-center gap-5">
+      <h2 className="text-center text-2xl font-bold text-white drop-shadow-lg md:text-4xl lg:text-5xl">
+        <Markup html={question} />
+      </h2>
+
+      {Boolean(image) && (
+        <img
+          alt={toPlainText(question)}
+          src={resolveMediaUrl(image)}
+          className="mb-2 max-h-60 w-auto rounded-md px-4 sm:max-h-100"
+        />
+      )}
+    </div>
+
+    <div className="mx-auto mb-4 grid w-full max-w-7xl grid-cols-2 gap-1 rounded-full px-2 text-lg font-bold text-white md:text-xl">
+      {answers.map((answer, key) => (
+        <AnswerButton
+          key={key}
+          className={clsx(ANSWERS_COLORS[key], "cursor-default", {
+            "opacity-25 grayscale": eliminated.includes(key),
+          })}
+          icon={ANSWERS_ICONS[key]}
+          disabled
+        >
+          <Markup html={answer} />
+        </AnswerButton>
+      ))}
+    </div>
+  </div>
+)
+
+export default Elimination
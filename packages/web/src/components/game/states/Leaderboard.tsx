@@ -1,4 +1,7 @@
 import { ManagerStatusDataMap } from "@rahoot/common/types/game/status"
+import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
+import { useManagerStore } from "@rahoot/web/stores/manager"
+import clsx from "clsx"
 import { AnimatePresence, motion, useSpring, useTransform } from "motion/react"
 import { useEffect, useState } from "react"
 
@@ -6,7 +9,13 @@ type Props = {
   data: ManagerStatusDataMap["SHOW_LEADERBOARD"]
 }
 
-const AnimatedPoints = ({ from, to }: { from: number; to: number }) => {
+type AnimatedPointsProps = {
+  from: number
+  to: number
+  formatted?: string
+}
+
+const AnimatedPoints = ({ from, to, formatted }: AnimatedPointsProps) => {
   const spring = useSpring(from, { stiffness: 1000, damping: 30 })
   const display = useTransform(spring, (value) => Math.round(value))
   const [displayValue, setDisplayValue] = useState(from)
@@ -20,13 +29,32 @@ const AnimatedPoints = ({ from, to }: { from: number; to: number }) => {
     return unsubscribe
   }, [to, spring, display])
 
-  return <span className="drop-shadow-md">{displayValue}</span>
+  return (
+    <span className="drop-shadow-md">
+      {displayValue === to && formatted ? formatted : displayValue}
+    </span>
+  )
 }
 
-const Leaderboard = ({ data: { oldLeaderboard, leaderboard } }: Props) => {
+const Leaderboard = ({
+  data: { oldLeaderboard, leaderboard, mode, muted },
+}: Props) => {
   const [displayedLeaderboard, setDisplayedLeaderboard] =
     useState(oldLeaderboard)
   const [isAnimating, setIsAnimating] = useState(false)
+  const [mutedIds, setMutedIds] = useState(muted)
+  const { socket } = useSocket()
+  const { gameId } = useManagerStore()
+
+  useEffect(() => {
+    setMutedIds(muted)
+  }, [muted])
+
+  useEvent("manager:playerMuted", ({ playerId, muted: isMuted }) => {
+    setMutedIds((ids) =>
+      isMuted ? [...ids, playerId] : ids.filter((id) => id !== playerId),
+    )
+  })
 
   useEffect(() => {
     setDisplayedLeaderboard(oldLeaderboard)
@@ -42,6 +70,31 @@ const Leaderboard = ({ data: { oldLeaderboard, leaderboard } }: Props) => {
     }
   }, [oldLeaderboard, leaderboard])
 
+  const handleAdjust = (playerId: string, username: string) => () => {
+    const input = window.prompt(`Points to add to ${username} (e.g. -500)`)
+    const delta = Number(input)
+
+    if (!gameId || !input || !Number.isFinite(delta)) {
+      return
+    }
+
+    socket?.emit("manager:adjustScore", { gameId, playerId, delta })
+  }
+
+  const handleMute = (playerId: string) => () => {
+    if (!gameId) {
+      return
+    }
+
+    const message = { gameId, playerId }
+
+    if (mutedIds.includes(playerId)) {
+      socket?.emit("manager:unmutePlayer", message)
+    } else {
+      socket?.emit("manager:mutePlayer", message)
+    }
+  }
+
   return (
     <section className="relative mx-auto flex w-full max-w-4xl flex-1 flex-col items-center justify-center px-2">
       <h2 className="mb-6 text-5xl font-bold text-white drop-shadow-md">
@@ -49,40 +102,70 @@ const Leaderboard = ({ data: { oldLeaderboard, leaderboard } }: Props) => {
       </h2>
       <div className="flex w-full flex-col gap-2">
         <AnimatePresence mode="popLayout">
-          {displayedLeaderboard.map(({ id, username, points }) => (
-            <motion.div
-              key={id}
-              layout
-              initial={{ opacity: 0, y: 50 }}
-              animate={{
-                opacity: 1,
-                y: 0,
-              }}
-              exit={{
-                opacity: 0,
-                y: 50,
-                transition: { duration: 0.2 },
-              }}
-              transition={{
-                layout: {
-                  type: "spring",
-                  stiffness: 350,
-                  damping: 25,
-                },
-              }}
-              className="bg-primary flex w-full justify-between rounded-md p-3 text-2xl font-bold text-white"
-            >
-              <span className="drop-shadow-md">{username}</span>
-              {isAnimating ? (
-                <AnimatedPoints
-                  from={oldLeaderboard.find((u) => u.id === id)?.points || 0}
-                  to={leaderboard.find((u) => u.id === id)?.points || 0}
-                />
-              ) : (
-                <span className="drop-shadow-md">{points}</span>
-              )}
-            </motion.div>
-          ))}
+          {displayedLeaderboard.map(
+            ({ id, username, formattedPoints, correctAnswers }) => (
+              <motion.div
+                key={id}
+                layout
+                initial={{ opacity: 0, y: 50 }}
+                animate={{
+                  opacity: 1,
+                  y: 0,
+                }}
+                exit={{
+                  opacity: 0,
+                  y: 50,
+                  transition: { duration: 0.2 },
+                }}
+                transition={{
+                  layout: {
+                    type: "spring",
+                    stiffness: 350,
+                    damping: 25,
+                  },
+                }}
+                className="bg-primary flex w-full justify-between rounded-md p-3 text-2xl font-bold text-white"
+              >
+                <span className="flex items-center gap-3">
+                  <span
+                    className={clsx("drop-shadow-md", {
+                      "opacity-50": mutedIds.includes(id),
+                    })}
+                  >
+                    {username}
+                  </span>
+                  <button
+                    className="rounded bg-black/40 px-2 py-1 text-sm"
+                    onClick={handleMute(id)}
+                  >
+                    {mutedIds.includes(id) ? "Unmute" : "Mute"}
+                  </button>
+                </span>
+                <button
+                  className="flex items-center gap-4"
+                  title="Adjust points"
+                  onClick={handleAdjust(id, username)}
+                >
+                  {mode === "accuracy" && (
+                    <span className="text-lg drop-shadow-md">
+                      {`${correctAnswers} correct`}
+                    </span>
+                  )}
+                  {isAnimating ? (
+                    <AnimatedPoints
+                      from={
+                        oldLeaderboard.find((u) => u.id === id)?.points || 0
+                      }
+                      to={leaderboard.find((u) => u.id === id)?.points || 0}
+                      formatted={formattedPoints}
+                    />
+                  ) : (
+                    <span className="drop-shadow-md">{formattedPoints}</span>
+                  )}
+                </button>
+              </motion.div>
+            ),
+          )}
         </AnimatePresence>
       </div>
     </section>
@@ -17,7 +17,7 @@ type Props = {
   data: ManagerStatusDataMap["FINISHED"]
 }
 
-const Podium = ({ data: { subject, top } }: Props) => {
+const Podium = ({ data: { subject, top, timeExpired, tags } }: Props) => {
   const [apparition, setApparition] = useState(0)
 
   const { width, height } = useScreenSize()
@@ -103,6 +103,23 @@ const Podium = ({ data: { subject, top } }: Props) => {
         <h2 className="anim-show text-center text-3xl font-bold text-white drop-shadow-lg md:text-4xl lg:text-5xl">
           {subject}
         </h2>
+        {timeExpired && (
+          <p className="anim-show rounded-md bg-black/40 px-4 py-2 text-lg font-semibold text-white">
+            Time&apos;s up! The game ended before the last question.
+          </p>
+        )}
+        {tags && apparition >= 3 && (
+          <ul className="anim-show flex flex-wrap justify-center gap-2">
+            {tags.map(({ tag, correct, total }) => (
+              <li
+                key={tag}
+                className="rounded-md bg-black/40 px-3 py-1 text-sm font-semibold text-white"
+              >
+                {tag}: {total > 0 ? Math.round((correct / total) * 100) : 0}%
+              </li>
+            ))}
+          </ul>
+        )}
 
         <div
           style={{ gridTemplateColumns: `repeat(${top.length}, 1fr)` }}
@@ -130,7 +147,7 @@ const Podium = ({ data: { subject, top } }: Props) => {
                   <span className="drop-shadow-md">2</span>
                 </p>
                 <p className="text-2xl font-bold text-white drop-shadow-lg">
-                  {top[1].points}
+                  {top[1].formattedPoints}
                 </p>
               </div>
             </div>
@@ -160,7 +177,7 @@ const Podium = ({ data: { subject, top } }: Props) => {
                 <span className="drop-shadow-md">1</span>
               </p>
               <p className="text-2xl font-bold text-white drop-shadow-lg">
-                {top[0].points}
+                {top[0].formattedPoints}
               </p>
             </div>
           </div>
@@ -190,7 +207,7 @@ const Podium = ({ data: { subject, top } }: Props) => {
                 </p>
 
                 <p className="text-2xl font-bold text-white drop-shadow-lg">
-                  {top[2].points}
+                  {top[2].formattedPoints}
                 </p>
               </div>
             </div>
@@ -1,4 +1,5 @@
 import { CommonStatusDataMap } from "@rahoot/common/types/game/status"
+import Markup from "@rahoot/web/components/Markup"
 import { ANSWERS_COLORS, ANSWERS_ICONS } from "@rahoot/web/utils/constants"
 import clsx from "clsx"
 import { createElement } from "react"
@@ -7,10 +8,22 @@ type Props = {
   data: CommonStatusDataMap["SHOW_PREPARED"]
 }
 
-const Prepared = ({ data: { totalAnswers, questionNumber } }: Props) => (
+const Prepared = ({
+  data: { totalAnswers, questionNumber, isLast, warmup, answers, solution },
+}: Props) => (
   <section className="anim-show relative mx-auto flex w-full max-w-7xl flex-1 flex-col items-center justify-center">
+    {isLast && (
+      <p className="bg-primary mb-6 rounded-md px-4 py-1 text-lg font-bold text-white">
+        Final question!
+      </p>
+    )}
+    {warmup && (
+      <p className="mb-6 rounded-md bg-black/40 px-4 py-1 text-lg font-bold text-white">
+        Just for practice, points don&apos;t count
+      </p>
+    )}
     <h2 className="anim-show mb-20 text-center text-3xl font-bold text-white drop-shadow-lg md:text-4xl lg:text-5xl">
-      Question #{questionNumber}
+      {warmup ? "Warmup question" : `Question #${questionNumber}`}
     </h2>
     <div className="anim-quizz grid aspect-square w-60 grid-cols-2 gap-4 rounded-2xl bg-gray-700 p-5 md:w-60">
       {[...Array(totalAnswers)].map((_, key) => (
@@ -25,6 +38,18 @@ const Prepared = ({ data: { totalAnswers, questionNumber } }: Props) => (
         </div>
       ))}
     </div>
+    {answers && (
+      <ol className="mt-8 flex flex-col gap-1 rounded-md bg-black/40 px-6 py-3 text-lg font-bold text-white">
+        {answers.map((answer, key) => (
+          <li
+            key={key}
+            className={clsx({ "opacity-50": !solution?.includes(key) })}
+          >
+            <Markup html={answer} />
+          </li>
+        ))}
+      </ol>
+    )}
   </section>
 )
 
@@ -1,7 +1,16 @@
 "use client"
 
 import { CommonStatusDataMap } from "@rahoot/common/types/game/status"
-import { SFX_SHOW_SOUND } from "@rahoot/web/utils/constants"
+import AnswerButton from "@rahoot/web/components/AnswerButton"
+import Markup from "@rahoot/web/components/Markup"
+import {
+  ANSWERS_COLORS,
+  ANSWERS_ICONS,
+  SFX_SHOW_SOUND,
+} from "@rahoot/web/utils/constants"
+import { toPlainText } from "@rahoot/web/utils/markup"
+import { resolveMediaUrl } from "@rahoot/web/utils/media"
+import clsx from "clsx"
 import { useEffect } from "react"
 import useSound from "use-sound"
 
@@ -9,7 +18,19 @@ type Props = {
   data: CommonStatusDataMap["SHOW_QUESTION"]
 }
 
-const Question = ({ data: { question, image, cooldown } }: Props) => {
+const Question = ({
+  data: {
+    question,
+    section,
+    isLast,
+    answers,
+    solution,
+    image,
+    media,
+    wager,
+    cooldown,
+  },
+}: Props) => {
   const [sfxShow] = useSound(SFX_SHOW_SOUND, { volume: 0.5 })
 
   useEffect(() => {
@@ -19,18 +40,51 @@ const Question = ({ data: { question, image, cooldown } }: Props) => {
   return (
     <section className="relative mx-auto flex h-full w-full max-w-7xl flex-1 flex-col items-center px-4">
       <div className="flex flex-1 flex-col items-center justify-center gap-5">
+        {section && (
+          <p className="rounded-md bg-black/40 px-4 py-1 text-lg font-bold text-white">
+            {section}
+          </p>
+        )}
+        {isLast && (
+          <p className="bg-primary rounded-md px-4 py-1 text-lg font-bold text-white">
+            Final question!
+          </p>
+        )}
+        {wager && (
+          <p className="bg-primary rounded-md px-4 py-1 text-lg font-bold text-white">
+            Double or nothing
+          </p>
+        )}
         <h2 className="anim-show text-center text-3xl font-bold text-white drop-shadow-lg md:text-4xl lg:text-5xl">
-          {question}
+          <Markup html={question} />
         </h2>
 
         {Boolean(image) && (
           <img
-            alt={question}
-            src={image}
+            alt={toPlainText(question)}
+            src={resolveMediaUrl(image)}
+            width={media?.image?.width}
+            height={media?.image?.height}
             className="max-h-60 w-auto rounded-md sm:max-h-100"
           />
         )}
       </div>
+      {answers && (
+        <div className="mb-4 grid w-full grid-cols-2 gap-1 text-lg font-bold text-white md:text-xl">
+          {answers.map((answer, key) => (
+            <AnswerButton
+              key={key}
+              className={clsx(ANSWERS_COLORS[key], "cursor-default", {
+                "opacity-65": solution && !solution.includes(key),
+              })}
+              icon={ANSWERS_ICONS[key]}
+              disabled
+            >
+              <Markup html={answer} />
+            </AnswerButton>
+          ))}
+        </div>
+      )}
       <div
         className="bg-primary mb-20 h-4 self-start justify-self-end rounded-full"
         style={{ animation: `progressBar ${cooldown}s linear forwards` }}
@@ -2,6 +2,7 @@
 
 import { ManagerStatusDataMap } from "@rahoot/common/types/game/status"
 import AnswerButton from "@rahoot/web/components/AnswerButton"
+import Markup from "@rahoot/web/components/Markup"
 import {
   ANSWERS_COLORS,
   ANSWERS_ICONS,
@@ -18,9 +19,9 @@ type Props = {
 }
 
 const Responses = ({
-  data: { question, answers, responses, correct },
+  data: { question, answers, responses, correct, answerers, numeric },
 }: Props) => {
-  const [percentages, setPercentages] = useState<Record<string, string>>({})
+  const [percentages, setPercentages] = useState<string[]>([])
   const [isMusicPlaying, setIsMusicPlaying] = useState(false)
 
   const [sfxResults] = useSound(SFX_RESULTS_SOUND, {
@@ -58,41 +59,80 @@ const Responses = ({
     <div className="flex h-full flex-1 flex-col justify-between">
       <div className="mx-auto inline-flex h-full w-full max-w-7xl flex-1 flex-col items-center justify-center gap-5">
         <h2 className="text-center text-2xl font-bold text-white drop-shadow-lg md:text-4xl lg:text-5xl">
-          {question}
+          <Markup html={question} />
         </h2>
 
-        <div
-          className={`mt-8 grid h-40 w-full max-w-3xl gap-4 px-2`}
-          style={{ gridTemplateColumns: `repeat(${answers.length}, 1fr)` }}
-        >
-          {answers.map((_, key) => (
-            <div
-              key={key}
-              className={clsx(
-                "flex flex-col justify-end self-end overflow-hidden rounded-md",
-                ANSWERS_COLORS[key],
-              )}
-              style={{ height: percentages[key] }}
-            >
-              <span className="w-full bg-black/10 text-center text-lg font-bold text-white drop-shadow-md">
-                {responses[key] || 0}
-              </span>
-            </div>
-          ))}
-        </div>
+        {numeric && (
+          <div className="mt-8 flex flex-col items-center gap-4 text-white">
+            <p className="rounded-md bg-black/40 px-4 py-2 text-2xl font-bold">
+              {`Answer: ${numeric.target}`}
+              {numeric.tolerance > 0 && ` (± ${numeric.tolerance})`}
+            </p>
+            {numeric.summary ? (
+              <div className="grid grid-cols-3 gap-4 text-center text-xl font-bold">
+                <p className="rounded-md bg-black/40 px-4 py-2">
+                  {`Min ${numeric.summary.min}`}
+                </p>
+                <p className="rounded-md bg-black/40 px-4 py-2">
+                  {`Median ${numeric.summary.median}`}
+                </p>
+                <p className="rounded-md bg-black/40 px-4 py-2">
+                  {`Max ${numeric.summary.max}`}
+                </p>
+              </div>
+            ) : (
+              <p className="text-xl font-bold">No answers</p>
+            )}
+          </div>
+        )}
+
+        {!numeric && (
+          <div
+            className={`mt-8 grid h-40 w-full max-w-3xl gap-4 px-2`}
+            style={{ gridTemplateColumns: `repeat(${answers.length}, 1fr)` }}
+          >
+            {answers.map((_, key) => (
+              <div
+                key={key}
+                className={clsx(
+                  "flex min-h-7 flex-col justify-end self-end overflow-hidden rounded-md",
+                  ANSWERS_COLORS[key],
+                )}
+                style={{ height: percentages[key] }}
+              >
+                <span className="w-full bg-black/10 text-center text-lg font-bold text-white drop-shadow-md">
+                  {responses[key]}
+                </span>
+              </div>
+            ))}
+          </div>
+        )}
       </div>
 
       <div>
+        {answerers && answerers.length > 0 && (
+          <div className="mx-auto mb-4 flex w-full max-w-7xl flex-wrap justify-center gap-2 px-2">
+            {answerers.map((username, key) => (
+              <span
+                key={key}
+                className="rounded-md bg-black/40 px-3 py-1 font-bold text-white"
+              >
+                {username}
+              </span>
+            ))}
+          </div>
+        )}
+
         <div className="mx-auto mb-4 grid w-full max-w-7xl grid-cols-2 gap-1 rounded-full px-2 text-lg font-bold text-white md:text-xl">
           {answers.map((answer, key) => (
             <AnswerButton
               key={key}
               className={clsx(ANSWERS_COLORS[key], {
-                "opacity-65": responses && correct !== key,
+                "opacity-65": responses && !correct.includes(key),
               })}
               icon={ANSWERS_ICONS[key]}
             >
-              {answer}
+              <Markup html={answer} />
             </AnswerButton>
           ))}
         </div>
@@ -1,11 +1,14 @@
 "use client"
 
+import { LeaderboardEntry } from "@rahoot/common/types/game"
 import { CommonStatusDataMap } from "@rahoot/common/types/game/status"
 import CricleCheck from "@rahoot/web/components/icons/CricleCheck"
 import CricleXmark from "@rahoot/web/components/icons/CricleXmark"
+import { useEvent } from "@rahoot/web/contexts/socketProvider"
 import { usePlayerStore } from "@rahoot/web/stores/player"
 import { SFX_RESULTS_SOUND } from "@rahoot/web/utils/constants"
-import { useEffect } from "react"
+import { formatNumber } from "@rahoot/web/utils/number"
+import { useEffect, useState } from "react"
 import useSound from "use-sound"
 
 type Props = {
@@ -13,20 +16,37 @@ type Props = {
 }
 
 const Result = ({
-  data: { correct, message, points, myPoints, rank, aheadOfMe },
+  data: {
+    correct,
+    message,
+    points,
+    myPoints,
+    formattedPoints,
+    formattedMyPoints,
+    rank,
+    aheadOfMe,
+    streak,
+  },
 }: Props) => {
   const player = usePlayerStore()
+  const [leaderboard, setLeaderboard] = useState<LeaderboardEntry[]>([])
 
   const [sfxResults] = useSound(SFX_RESULTS_SOUND, {
     volume: 0.2,
   })
 
   useEffect(() => {
-    player.updatePoints(myPoints)
+    if (myPoints !== undefined) {
+      player.updatePoints(myPoints, formattedMyPoints)
+    }
 
     sfxResults()
   }, [sfxResults])
 
+  useEvent("player:updateLeaderboard", ({ leaderboard }) => {
+    setLeaderboard(leaderboard.slice(0, 5))
+  })
+
   return (
     <section className="anim-show relative mx-auto flex w-full max-w-7xl flex-1 flex-col items-center justify-center">
       {correct ? (
@@ -37,14 +57,34 @@ const Result = ({
       <h2 className="mt-1 text-4xl font-bold text-white drop-shadow-lg">
         {message}
       </h2>
-      <p className="mt-1 text-xl font-bold text-white drop-shadow-lg">
-        {`You are top ${rank}${aheadOfMe ? `, behind ${aheadOfMe}` : ""}`}
-      </p>
-      {correct && (
+      {rank !== undefined && (
+        <p className="mt-1 text-xl font-bold text-white drop-shadow-lg">
+          {`You are top ${formatNumber(rank)}${aheadOfMe ? `, behind ${aheadOfMe}` : ""}`}
+        </p>
+      )}
+      {points !== undefined && (correct || points < 0) && (
         <span className="mt-2 rounded bg-black/40 px-4 py-2 text-2xl font-bold text-white drop-shadow-lg">
-          +{points}
+          {points < 0 ? formattedPoints : `+${formattedPoints}`}
         </span>
       )}
+      {streak > 1 && (
+        <p className="mt-2 text-xl font-bold text-white drop-shadow-lg">
+          {`🔥 ${streak} in a row`}
+        </p>
+      )}
+      {leaderboard.length > 0 && (
+        <div className="mt-6 flex w-full max-w-md flex-col gap-2 px-2">
+          {leaderboard.map(({ id, username, formattedPoints }) => (
+            <div
+              key={id}
+              className="anim-show bg-primary flex w-full justify-between rounded-md px-3 py-2 text-lg font-bold text-white"
+            >
+              <span className="drop-shadow-md">{username}</span>
+              <span className="drop-shadow-md">{formattedPoints}</span>
+            </div>
+          ))}
+        </div>
+      )}
     </section>
   )
 }
@@ -1,25 +1,51 @@
 "use client"
 
-import { Player } from "@rahoot/common/types/game"
+import { LobbyOrder, Player } from "@rahoot/common/types/game"
 import { ManagerStatusDataMap } from "@rahoot/common/types/game/status"
 import { useEvent, useSocket } from "@rahoot/web/contexts/socketProvider"
 import { useManagerStore } from "@rahoot/web/stores/manager"
-import { useState } from "react"
+import clsx from "clsx"
+import { MouseEvent, useState } from "react"
+import toast from "react-hot-toast"
 import QRCode from "react-qr-code"
 
 type Props = {
   data: ManagerStatusDataMap["SHOW_ROOM"]
 }
 
-const Room = ({ data: { text, inviteCode } }: Props) => {
+const insertPlayer = (
+  players: Player[],
+  player: Player,
+  order?: LobbyOrder,
+) => {
+  if (order === "alphabetical") {
+    return [...players, player].sort((a, b) =>
+      a.username.localeCompare(b.username),
+    )
+  }
+
+  if (order === "random") {
+    const index = Math.floor(Math.random() * (players.length + 1))
+
+    return [...players.slice(0, index), player, ...players.slice(index)]
+  }
+
+  return [...players, player]
+}
+
+const Room = ({
+  data: { text, inviteCode, lobbyOrder, players },
+}: Props) => {
   const { gameId } = useManagerStore()
   const { socket, webUrl } = useSocket()
-  const { players } = useManagerStore()
-  const [playerList, setPlayerList] = useState<Player[]>(players)
+  const { players: storedPlayers } = useManagerStore()
+  const [playerList, setPlayerList] = useState<Player[]>(
+    players || storedPlayers,
+  )
   const [totalPlayers, setTotalPlayers] = useState(0)
 
   useEvent("manager:newPlayer", (player) => {
-    setPlayerList([...playerList, player])
+    setPlayerList(insertPlayer(playerList, player, lobbyOrder))
   })
 
   useEvent("manager:removePlayer", (playerId) => {
@@ -30,8 +56,16 @@ const Room = ({ data: { text, inviteCode } }: Props) => {
     setPlayerList(playerList.filter((p) => p.id !== playerId))
   })
 
-  useEvent("game:totalPlayers", (total) => {
-    setTotalPlayers(total)
+  useEvent("manager:playerMuted", ({ playerId, muted }) => {
+    setPlayerList(
+      playerList.map((p) => (p.id === playerId ? { ...p, muted } : p)),
+    )
+  })
+
+  useEvent("game:totalPlayers", (data) => {
+    if (data.gameId === gameId) {
+      setTotalPlayers(data.count)
+    }
   })
 
   const handleKick = (playerId: string) => () => {
@@ -45,6 +79,45 @@ const Room = ({ data: { text, inviteCode } }: Props) => {
     })
   }
 
+  const handleCopyCoHostLink = () => {
+    navigator.clipboard
+      .writeText(`${webUrl}/manager?cohost=${gameId}`)
+      .then(() => toast.success("Co-host link copied"))
+      .catch(() => toast.error("Failed to copy the co-host link"))
+  }
+
+  const handleMute = (player: Player) => (event: MouseEvent) => {
+    event.stopPropagation()
+
+    if (!gameId) {
+      return
+    }
+
+    const message = { gameId, playerId: player.id }
+
+    if (player.muted) {
+      socket?.emit("manager:unmutePlayer", message)
+    } else {
+      socket?.emit("manager:mutePlayer", message)
+    }
+  }
+
+  const handleMessage = (player: Player) => (event: MouseEvent) => {
+    event.stopPropagation()
+
+    const message = window.prompt(`Message to ${player.username}`)
+
+    if (!gameId || !message?.trim()) {
+      return
+    }
+
+    socket?.emit("manager:messagePlayer", {
+      gameId,
+      playerId: player.id,
+      message,
+    })
+  }
+
   return (
     <section className="relative mx-auto flex w-full max-w-7xl flex-1 flex-col items-center justify-center px-2">
       <div className="mb-10 flex flex-col-reverse items-center gap-3 md:flex-row md:items-stretch">
@@ -78,16 +151,38 @@ const Room = ({ data: { text, inviteCode } }: Props) => {
         </span>
       </div>
 
+      <button
+        className="mb-6 rounded-md bg-black/40 px-4 py-2 font-bold text-white"
+        onClick={handleCopyCoHostLink}
+      >
+        Copy co-host link
+      </button>
+
       <div className="flex flex-wrap gap-3">
         {playerList.map((player) => (
           <div
             key={player.id}
-            className="shadow-inset bg-primary rounded-md px-4 py-3 font-bold text-white"
+            className={clsx(
+              "shadow-inset bg-primary flex items-center gap-3 rounded-md px-4 py-3 font-bold text-white",
+              { "opacity-50": player.muted },
+            )}
             onClick={handleKick(player.id)}
           >
             <span className="cursor-pointer text-3xl drop-shadow-md hover:line-through">
               {player.username}
             </span>
+            <button
+              className="rounded bg-black/40 px-2 py-1 text-sm"
+              onClick={handleMute(player)}
+            >
+              {player.muted ? "Unmute" : "Mute"}
+            </button>
+            <button
+              className="rounded bg-black/40 px-2 py-1 text-sm"
+              onClick={handleMessage(player)}
+            >
+              Message
+            </button>
           </div>
         ))}
       </div>
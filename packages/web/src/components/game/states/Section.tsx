@@ -0,0 +1,18 @@
+import { CommonStatusDataMap } from "@rahoot/common/types/game/status"
+
+type Props = {
+  data: CommonStatusDataMap["SHOW_SECTION"]
+}
+
+const Section = ({ data: { title, questionNumber } }: Props) => (
+  <section className="anim-show relative mx-auto flex w-full max-w-7xl flex-1 flex-col items-center justify-center">
+    <p className="mb-4 text-xl font-bold text-white/80 drop-shadow-lg md:text-2xl">
+      Starting at question #{questionNumber}
+    </p>
+    <h2 className="text-center text-4xl font-bold text-white drop-shadow-lg md:text-5xl lg:text-6xl">
+      {title}
+    </h2>
+  </section>
+)
+
+export default Section
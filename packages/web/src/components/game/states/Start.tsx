@@ -3,7 +3,9 @@
 import { CommonStatusDataMap } from "@rahoot/common/types/game/status"
 import { useEvent } from "@rahoot/web/contexts/socketProvider"
 import { SFX_BOUMP_SOUND } from "@rahoot/web/utils/constants"
+import { formatNumber } from "@rahoot/web/utils/number"
 import clsx from "clsx"
+import { useParams } from "next/navigation"
 import { useState } from "react"
 import useSound from "use-sound"
 
@@ -12,6 +14,7 @@ type Props = {
 }
 
 const Start = ({ data: { time, subject } }: Props) => {
+  const { gameId }: { gameId?: string } = useParams()
   const [showTitle, setShowTitle] = useState(true)
   const [cooldown, setCooldown] = useState(time)
 
@@ -19,14 +22,22 @@ const Start = ({ data: { time, subject } }: Props) => {
     volume: 0.2,
   })
 
-  useEvent("game:startCooldown", () => {
+  useEvent("game:startCooldown", (data) => {
+    if (data.gameId !== gameId) {
+      return
+    }
+
     sfxBoump()
     setShowTitle(false)
   })
 
-  useEvent("game:cooldown", (sec) => {
+  useEvent("game:cooldown", (data) => {
+    if (data.gameId !== gameId) {
+      return
+    }
+
     sfxBoump()
-    setCooldown(sec)
+    setCooldown(data.count)
   })
 
   return (
@@ -46,7 +57,7 @@ const Start = ({ data: { time, subject } }: Props) => {
             }}
           ></div>
           <span className="absolute text-6xl font-bold text-white drop-shadow-md md:text-8xl">
-            {cooldown}
+            {formatNumber(cooldown)}
           </span>
         </>
       )}
@@ -1,17 +1,34 @@
 import { PlayerStatusDataMap } from "@rahoot/common/types/game/status"
 import Loader from "@rahoot/web/components/Loader"
+import { usePlayerStore } from "@rahoot/web/stores/player"
 
 type Props = {
   data: PlayerStatusDataMap["WAIT"]
 }
 
-const Wait = ({ data: { text } }: Props) => (
-  <section className="relative mx-auto flex w-full max-w-7xl flex-1 flex-col items-center justify-center">
-    <Loader />
-    <h2 className="mt-5 text-center text-3xl font-bold text-white drop-shadow-lg md:text-4xl lg:text-5xl">
-      {text}
-    </h2>
-  </section>
-)
+const Wait = ({ data: { text } }: Props) => {
+  const { lobbyPlayers } = usePlayerStore()
+
+  return (
+    <section className="relative mx-auto flex w-full max-w-7xl flex-1 flex-col items-center justify-center">
+      <Loader />
+      <h2 className="mt-5 text-center text-3xl font-bold text-white drop-shadow-lg md:text-4xl lg:text-5xl">
+        {text}
+      </h2>
+      {lobbyPlayers.length > 0 && (
+        <div className="mt-6 flex flex-wrap justify-center gap-2 px-4">
+          {lobbyPlayers.map((username, index) => (
+            <span
+              key={index}
+              className="rounded-md bg-black/40 px-3 py-1 font-bold text-white"
+            >
+              {username}
+            </span>
+          ))}
+        </div>
+      )}
+    </section>
+  )
+}
 
 export default Wait
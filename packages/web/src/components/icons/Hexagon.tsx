@@ -0,0 +1,17 @@
+type Props = {
+  className?: string
+  fill?: string
+}
+
+const Hexagon = ({ className, fill = "#FFF" }: Props) => (
+  <svg
+    className={className}
+    fill={fill}
+    viewBox="0 0 512 512"
+    xmlns="http://www.w3.org/2000/svg"
+  >
+    <polygon points="256 32 450 144 450 368 256 480 62 368 62 144" />
+  </svg>
+)
+
+export default Hexagon
@@ -0,0 +1,17 @@
+type Props = {
+  className?: string
+  fill?: string
+}
+
+const Star = ({ className, fill = "#FFF" }: Props) => (
+  <svg
+    className={className}
+    fill={fill}
+    viewBox="0 0 512 512"
+    xmlns="http://www.w3.org/2000/svg"
+  >
+    <polygon points="256 32 322 182 484 196 362 304 398 464 256 380 114 464 150 304 28 196 190 182" />
+  </svg>
+)
+
+export default Star
@@ -5,6 +5,7 @@ import {
   ClientToServerEvents,
   ServerToClientEvents,
 } from "@rahoot/common/types/game/socket"
+import { BASE_PATH } from "@rahoot/web/utils/constants"
 import ky from "ky"
 import React, {
   createContext,
@@ -39,7 +40,9 @@ const SocketContext = createContext<SocketContextValue>({
 })
 
 const getSocketServer = async () =>
-  await ky.get("/env").json<{ webUrl: string; socketUrl: string }>()
+  await ky
+    .get(`${BASE_PATH}/env`)
+    .json<{ webUrl: string; socketUrl: string; socketPath: string }>()
 
 const getClientId = (): string => {
   try {
@@ -73,9 +76,10 @@ export const SocketProvider = ({ children }: { children: React.ReactNode }) => {
 
     const initSocket = async () => {
       try {
-        const { webUrl, socketUrl } = await getSocketServer()
+        const { webUrl, socketUrl, socketPath } = await getSocketServer()
 
         s = io(socketUrl, {
+          path: socketPath,
           autoConnect: false,
           auth: {
             clientId,
@@ -93,6 +97,16 @@ export const SocketProvider = ({ children }: { children: React.ReactNode }) => {
           setIsConnected(false)
         })
 
+        s.on("game:serverInfo", ({ version, commit }) => {
+          console.log(
+            `Connected to Rahoot server ${version}${commit ? ` (${commit})` : ""}`,
+          )
+        })
+
+        s.on("game:protocolError", (message) => {
+          console.warn("Message rejected by the server:", message)
+        })
+
         s.on("connect_error", (err) => {
           console.error("Connection error:", err.message)
         })
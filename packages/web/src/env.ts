@@ -5,11 +5,25 @@ const env = createEnv({
   server: {
     WEB_ORIGIN: z.string().default("http://localhost:3000"),
     SOCKET_URL: z.string().default("http://localhost:3001"),
+    BASE_PATH: z
+      .string()
+      .default("")
+      .transform((path) => path.replace(/\/+$/u, "")),
+    APP_NAME: z.string().default("Rahoot !"),
+    THEME_COLOR: z.string().default("#ff9900"),
+    WS_PATH: z
+      .string()
+      .regex(/^\//u, "WS_PATH must start with /")
+      .default("/socket.io"),
   },
 
   runtimeEnv: {
     WEB_ORIGIN: process.env.WEB_ORIGIN,
     SOCKET_URL: process.env.SOCKET_URL,
+    BASE_PATH: process.env.BASE_PATH,
+    APP_NAME: process.env.APP_NAME,
+    THEME_COLOR: process.env.THEME_COLOR,
+    WS_PATH: process.env.WS_PATH,
   },
 })
 
@@ -1,4 +1,4 @@
-import { Player } from "@rahoot/common/types/game"
+import { Player, QuestionReview } from "@rahoot/common/types/game"
 import { StatusDataMap } from "@rahoot/common/types/game/status"
 import { createStatus, Status } from "@rahoot/web/utils/createStatus"
 import { create } from "zustand"
@@ -7,11 +7,16 @@ type ManagerStore<T> = {
   gameId: string | null
   status: Status<T> | null
   players: Player[]
+  outline: string[]
+  review: QuestionReview[]
 
   setGameId: (_gameId: string | null) => void
   setStatus: <K extends keyof T>(_name: K, _data: T[K]) => void
   resetStatus: () => void
   setPlayers: (_players: Player[]) => void
+  updatePlayer: (_playerId: string, _player: Partial<Player>) => void
+  setOutline: (_outline: string[]) => void
+  setReview: (_review: QuestionReview[]) => void
 
   reset: () => void
 }
@@ -20,6 +25,8 @@ const initialState = {
   gameId: null,
   status: null,
   players: [],
+  outline: [],
+  review: [],
 }
 
 export const useManagerStore = create<ManagerStore<StatusDataMap>>((set) => ({
@@ -31,6 +38,15 @@ export const useManagerStore = create<ManagerStore<StatusDataMap>>((set) => ({
   resetStatus: () => set({ status: null }),
 
   setPlayers: (players) => set({ players }),
+  updatePlayer: (playerId, player) =>
+    set((state) => ({
+      players: state.players.map((p) =>
+        p.id === playerId ? { ...p, ...player } : p,
+      ),
+    })),
+
+  setOutline: (outline) => set({ outline }),
+  setReview: (review) => set({ review }),
 
   reset: () => set(initialState),
 }))
@@ -1,3 +1,4 @@
+import { Lifeline } from "@rahoot/common/types/game"
 import { StatusDataMap } from "@rahoot/common/types/game/status"
 import { createStatus, Status } from "@rahoot/web/utils/createStatus"
 import { create } from "zustand"
@@ -5,21 +6,26 @@ import { create } from "zustand"
 type PlayerState = {
   username?: string
   points?: number
+  formattedPoints?: string
 }
 
 type PlayerStore<T> = {
   gameId: string | null
   player: PlayerState | null
   status: Status<T> | null
+  lobbyPlayers: string[]
+  lifelines: Record<Lifeline, number> | null
 
   setGameId: (_gameId: string | null) => void
 
   setPlayer: (_state: PlayerState) => void
   login: (_gameId: string) => void
   join: (_username: string) => void
-  updatePoints: (_points: number) => void
+  updatePoints: (_points: number, _formattedPoints?: string) => void
 
   setStatus: <K extends keyof T>(_name: K, _data: T[K]) => void
+  setLobbyPlayers: (_usernames: string[]) => void
+  setLifelines: (_lifelines: Record<Lifeline, number>) => void
 
   reset: () => void
 }
@@ -28,6 +34,8 @@ const initialState = {
   gameId: null,
   player: null,
   status: null,
+  lobbyPlayers: [],
+  lifelines: null,
 }
 
 export const usePlayerStore = create<PlayerStore<StatusDataMap>>((set) => ({
@@ -48,12 +56,14 @@ export const usePlayerStore = create<PlayerStore<StatusDataMap>>((set) => ({
     }))
   },
 
-  updatePoints: (points) =>
+  updatePoints: (points, formattedPoints) =>
     set((state) => ({
-      player: { ...state.player, points },
+      player: { ...state.player, points, formattedPoints },
     })),
 
   setStatus: (name, data) => set({ status: createStatus(name, data) }),
+  setLobbyPlayers: (lobbyPlayers) => set({ lobbyPlayers }),
+  setLifelines: (lifelines) => set({ lifelines }),
 
   reset: () => set(initialState),
 }))
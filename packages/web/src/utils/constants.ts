@@ -1,4 +1,5 @@
 import Answers from "@rahoot/web/components/game/states/Answers"
+import Elimination from "@rahoot/web/components/game/states/Elimination"
 import Leaderboard from "@rahoot/web/components/game/states/Leaderboard"
 import Podium from "@rahoot/web/components/game/states/Podium"
 import Prepared from "@rahoot/web/components/game/states/Prepared"
@@ -6,23 +7,31 @@ import Question from "@rahoot/web/components/game/states/Question"
 import Responses from "@rahoot/web/components/game/states/Responses"
 import Result from "@rahoot/web/components/game/states/Result"
 import Room from "@rahoot/web/components/game/states/Room"
+import Section from "@rahoot/web/components/game/states/Section"
 import Start from "@rahoot/web/components/game/states/Start"
 import Wait from "@rahoot/web/components/game/states/Wait"
 
 import { STATUS } from "@rahoot/common/types/game/status"
 import Circle from "@rahoot/web/components/icons/Circle"
+import Hexagon from "@rahoot/web/components/icons/Hexagon"
 import Rhombus from "@rahoot/web/components/icons/Rhombus"
 import Square from "@rahoot/web/components/icons/Square"
+import Star from "@rahoot/web/components/icons/Star"
 import Triangle from "@rahoot/web/components/icons/Triangle"
 
+export const BASE_PATH = process.env.NEXT_PUBLIC_BASE_PATH || ""
+
 export const ANSWERS_COLORS = [
   "bg-red-500",
   "bg-blue-500",
   "bg-yellow-500",
   "bg-green-500",
+  "bg-purple-500",
+  "bg-orange-500",
 ]
 
-export const ANSWERS_ICONS = [Triangle, Rhombus, Circle, Square]
+// One color and icon per answer, up to MAX_ANSWER_OPTIONS
+export const ANSWERS_ICONS = [Triangle, Rhombus, Circle, Square, Star, Hexagon]
 
 export const GAME_STATES = {
   status: {
@@ -40,7 +49,9 @@ export const GAME_STATE_COMPONENTS = {
   [STATUS.SHOW_QUESTION]: Question,
   [STATUS.WAIT]: Wait,
   [STATUS.SHOW_START]: Start,
+  [STATUS.SHOW_ELIMINATION]: Elimination,
   [STATUS.SHOW_RESULT]: Result,
+  [STATUS.SHOW_SECTION]: Section,
   [STATUS.SHOW_PREPARED]: Prepared,
 }
 
@@ -52,25 +63,27 @@ export const GAME_STATE_COMPONENTS_MANAGER = {
   [STATUS.FINISHED]: Podium,
 }
 
-export const SFX_ANSWERS_MUSIC = "/sounds/answersMusic.mp3"
-export const SFX_ANSWERS_SOUND = "/sounds/answersSound.mp3"
-export const SFX_RESULTS_SOUND = "/sounds/results.mp3"
-export const SFX_SHOW_SOUND = "/sounds/show.mp3"
-export const SFX_BOUMP_SOUND = "/sounds/boump.mp3"
-export const SFX_PODIUM_THREE = "/sounds/three.mp3"
-export const SFX_PODIUM_SECOND = "/sounds/second.mp3"
-export const SFX_PODIUM_FIRST = "/sounds/first.mp3"
-export const SFX_SNEAR_ROOL = "/sounds/snearRoll.mp3"
+export const SFX_ANSWERS_MUSIC = `${BASE_PATH}/sounds/answersMusic.mp3`
+export const SFX_ANSWERS_SOUND = `${BASE_PATH}/sounds/answersSound.mp3`
+export const SFX_RESULTS_SOUND = `${BASE_PATH}/sounds/results.mp3`
+export const SFX_SHOW_SOUND = `${BASE_PATH}/sounds/show.mp3`
+export const SFX_BOUMP_SOUND = `${BASE_PATH}/sounds/boump.mp3`
+export const SFX_PODIUM_THREE = `${BASE_PATH}/sounds/three.mp3`
+export const SFX_PODIUM_SECOND = `${BASE_PATH}/sounds/second.mp3`
+export const SFX_PODIUM_FIRST = `${BASE_PATH}/sounds/first.mp3`
+export const SFX_SNEAR_ROOL = `${BASE_PATH}/sounds/snearRoll.mp3`
 
 export const MANAGER_SKIP_BTN = {
   [STATUS.SHOW_ROOM]: "Start Game",
   [STATUS.SHOW_START]: null,
+  [STATUS.SHOW_SECTION]: "Next",
   [STATUS.SHOW_PREPARED]: null,
   [STATUS.SHOW_QUESTION]: null,
   [STATUS.SELECT_ANSWER]: "Skip",
+  [STATUS.SHOW_ELIMINATION]: "Next",
   [STATUS.SHOW_RESULT]: null,
   [STATUS.SHOW_RESPONSES]: "Next",
   [STATUS.SHOW_LEADERBOARD]: "Next",
-  [STATUS.FINISHED]: null,
+  [STATUS.FINISHED]: "Rematch",
   [STATUS.WAIT]: null,
 }
@@ -0,0 +1,15 @@
+const ENTITIES: Record<string, string> = {
+  "&amp;": "&",
+  "&lt;": "<",
+  "&gt;": ">",
+  "&quot;": '"',
+  "&#39;": "'",
+}
+
+// Quiz text arrives escaped, or with formatting tags, for <Markup>: places
+// that only take text, such as alt attributes, need it back as plain text
+export const toPlainText = (html: string) =>
+  html
+    .replace(/<br>/gu, " ")
+    .replace(/<[^>]*>/gu, "")
+    .replace(/&(?:amp|lt|gt|quot|#39);/gu, (entity) => ENTITIES[entity])
@@ -0,0 +1,10 @@
+import { BASE_PATH } from "@rahoot/web/utils/constants"
+import { joinUrl } from "@rahoot/web/utils/url"
+
+export const resolveMediaUrl = (url?: string) => {
+  if (!url || url.startsWith("//") || /^[a-z][a-z\d+.-]*:/iu.test(url)) {
+    return url
+  }
+
+  return joinUrl(BASE_PATH, url)
+}
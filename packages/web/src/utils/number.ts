@@ -0,0 +1,6 @@
+// Countdowns and ranks follow the browser language, like the points the
+// server formats with the locale a player sends on login
+export const formatNumber = (value: number, locale?: string) =>
+  new Intl.NumberFormat(
+    locale ?? (typeof navigator === "undefined" ? "en" : navigator.language),
+  ).format(value)
@@ -0,0 +1,17 @@
+const getKey = (gameId: string) => `reconnect_token:${gameId}`
+
+export const saveReconnectToken = (gameId: string, token: string) => {
+  try {
+    localStorage.setItem(getKey(gameId), token)
+  } catch {
+    // Storage unavailable, reconnecting after a reload won't be possible
+  }
+}
+
+export const getReconnectToken = (gameId: string) => {
+  try {
+    return localStorage.getItem(getKey(gameId)) || ""
+  } catch {
+    return ""
+  }
+}
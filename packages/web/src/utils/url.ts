@@ -0,0 +1,2 @@
+export const joinUrl = (base: string, path: string) =>
+  `${base.replace(/\/+$/u, "")}/${path.replace(/^\/+/u, "")}`
@@ -0,0 +1,15 @@
+import { toPlainText } from "@rahoot/web/utils/markup"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+
+test("turns escaped quiz text back into plain text", () => {
+  assert.equal(toPlainText("Tom &amp; Jerry"), "Tom & Jerry")
+  assert.equal(toPlainText("Is 1 &lt; 2 ?"), "Is 1 < 2 ?")
+  assert.equal(toPlainText("&quot;Hi&quot; &#39;there&#39;"), `"Hi" 'there'`)
+  assert.equal(toPlainText("&amp;lt;"), "&lt;")
+})
+
+test("drops the formatting tags", () => {
+  assert.equal(toPlainText("H<sub>2</sub>O is <b>water</b>"), "H2O is water")
+  assert.equal(toPlainText("One<br>Two"), "One Two")
+})
@@ -0,0 +1,9 @@
+import { formatNumber } from "@rahoot/web/utils/number"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+
+test("formats countdowns and ranks with the given locale", () => {
+  assert.equal(formatNumber(3, "en"), "3")
+  assert.equal(formatNumber(3, "ar-EG"), "٣")
+  assert.equal(formatNumber(1234, "de"), "1.234")
+})
@@ -0,0 +1,17 @@
+import { joinUrl } from "@rahoot/web/utils/url"
+import assert from "node:assert/strict"
+import { test } from "node:test"
+
+test("joins with exactly one slash whatever the ends", () => {
+  assert.equal(joinUrl("/quiz", "images/a.png"), "/quiz/images/a.png")
+  assert.equal(joinUrl("/quiz", "/images/a.png"), "/quiz/images/a.png")
+  assert.equal(joinUrl("/quiz/", "images/a.png"), "/quiz/images/a.png")
+  assert.equal(joinUrl("/quiz/", "/images/a.png"), "/quiz/images/a.png")
+  assert.equal(joinUrl("/quiz//", "//images/a.png"), "/quiz/images/a.png")
+})
+
+test("keeps paths at the root without a base path", () => {
+  assert.equal(joinUrl("", "images/a.png"), "/images/a.png")
+  assert.equal(joinUrl("", "/images/a.png"), "/images/a.png")
+  assert.equal(joinUrl("/", "/images/a.png"), "/images/a.png")
+})